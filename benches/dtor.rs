@@ -0,0 +1,47 @@
+// Copyright 2012 The Rust Project Developers.
+// Copyright 2015 Guillaume Gomez
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Compares the cost of creating and dropping a `CVec` with a function pointer
+// destructor and with a boxed closure.
+
+#![feature(test)]
+
+extern crate c_vec;
+extern crate test;
+
+use c_vec::CVec;
+use test::{black_box, Bencher};
+
+fn free(_: *mut u8, _: usize) {}
+
+fn free_fn(p: *mut u8) {
+    free(p, 16)
+}
+
+#[bench]
+fn new_with_fn(b: &mut Bencher) {
+    let mut data = [0u8; 16];
+    let base = data.as_mut_ptr();
+    b.iter(|| {
+        let cvec = unsafe { CVec::new_with_fn(black_box(base), 16, free_fn) };
+        black_box(cvec)
+    });
+}
+
+#[bench]
+fn new_with_dtor(b: &mut Bencher) {
+    let mut data = [0u8; 16];
+    let base = data.as_mut_ptr();
+    b.iter(|| {
+        // A capturing closure, like most destructors, so boxing it allocates.
+        let len = black_box(16);
+        let cvec = unsafe { CVec::new_with_dtor(black_box(base), len, move |p| free(p, len)) };
+        black_box(cvec)
+    });
+}
@@ -0,0 +1,142 @@
+// Copyright 2012 The Rust Project Developers.
+// Copyright 2015 Guillaume Gomez
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::mem;
+use std::sync::atomic::{self, Ordering};
+
+use crate::CVec;
+
+/// Integer types which can be accessed through their matching atomic type (for
+/// example `u32` and [`AtomicU32`](atomic::AtomicU32)).
+///
+/// # Safety
+///
+/// `Atomic` must have the same size and bit validity as `Self`, and the
+/// methods must perform the matching atomic operation.
+pub unsafe trait AtomicElement: Copy {
+    /// The atomic type matching `Self`.
+    type Atomic;
+
+    /// Atomically loads the value pointed to by `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid and aligned for `Self::Atomic`.
+    unsafe fn atomic_load(ptr: *mut Self, order: Ordering) -> Self;
+
+    /// Atomically stores `val` into the value pointed to by `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid and aligned for `Self::Atomic`.
+    unsafe fn atomic_store(ptr: *mut Self, val: Self, order: Ordering);
+}
+
+macro_rules! atomic_element {
+    ($(#[$cfg:meta] $ty:ty => $atomic:ident,)*) => {
+        $(
+            #[$cfg]
+            unsafe impl AtomicElement for $ty {
+                type Atomic = atomic::$atomic;
+
+                unsafe fn atomic_load(ptr: *mut Self, order: Ordering) -> Self {
+                    atomic::$atomic::from_ptr(ptr).load(order)
+                }
+
+                unsafe fn atomic_store(ptr: *mut Self, val: Self, order: Ordering) {
+                    atomic::$atomic::from_ptr(ptr).store(val, order)
+                }
+            }
+        )*
+    };
+}
+
+// Not every target supports atomic operations of every width.
+atomic_element! {
+    #[cfg(target_has_atomic = "8")] u8 => AtomicU8,
+    #[cfg(target_has_atomic = "16")] u16 => AtomicU16,
+    #[cfg(target_has_atomic = "32")] u32 => AtomicU32,
+    #[cfg(target_has_atomic = "64")] u64 => AtomicU64,
+    #[cfg(target_has_atomic = "ptr")] usize => AtomicUsize,
+    #[cfg(target_has_atomic = "8")] i8 => AtomicI8,
+    #[cfg(target_has_atomic = "16")] i16 => AtomicI16,
+    #[cfg(target_has_atomic = "32")] i32 => AtomicI32,
+    #[cfg(target_has_atomic = "64")] i64 => AtomicI64,
+    #[cfg(target_has_atomic = "ptr")] isize => AtomicIsize,
+}
+
+impl<T: AtomicElement> CVec<T> {
+    fn atomic_ptr(&self, ofs: usize) -> *mut T {
+        assert!(ofs < self.len());
+        let ptr = unsafe { self.ptr_at(ofs) };
+        assert!((ptr as usize).is_multiple_of(mem::align_of::<T::Atomic>()));
+        ptr
+    }
+
+    /// Atomically loads the element at the given index with the given ordering.
+    ///
+    /// Panics if `ofs` is out of bounds, if the element isn't aligned for the
+    /// matching atomic type or if `order` is [`Release`](Ordering::Release) or
+    /// [`AcqRel`](Ordering::AcqRel).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let slice = &mut [0u32, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.load_atomic(1, Ordering::Acquire), 1);
+    /// ```
+    pub fn load_atomic(&self, ofs: usize, order: Ordering) -> T {
+        unsafe { T::atomic_load(self.atomic_ptr(ofs), order) }
+    }
+
+    /// Atomically stores `val` into the element at the given index with the
+    /// given ordering.
+    ///
+    /// Like any other write, it requires a mutable borrow of the vector so no
+    /// reference to its elements can be alive at the same time.
+    ///
+    /// Panics if `ofs` is out of bounds, if the element isn't aligned for the
+    /// matching atomic type or if `order` is [`Acquire`](Ordering::Acquire) or
+    /// [`AcqRel`](Ordering::AcqRel).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let slice = &mut [0u32, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// cvec.store_atomic(1, 5, Ordering::Release);
+    /// assert_eq!(cvec[1], 5);
+    /// ```
+    ///
+    /// A reference to an element can't be used across a store:
+    ///
+    /// ```compile_fail,E0502
+    /// use c_vec::CVec;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let slice = &mut [0u32, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let elem = &cvec[1];
+    /// cvec.store_atomic(1, 42, Ordering::Relaxed);
+    /// println!("{}", elem);
+    /// ```
+    pub fn store_atomic(&mut self, ofs: usize, val: T, order: Ordering) {
+        unsafe { T::atomic_store(self.atomic_ptr(ofs), val, order) }
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright 2012 The Rust Project Developers.
+// Copyright 2015 Guillaume Gomez
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::ptr;
+
+use crate::CSliceMut;
+
+/// Fills a [`CSliceMut`] one element after the other without going past its
+/// end.
+///
+/// The previous values of the slice are overwritten without being dropped.
+///
+/// You can get it from the [`CSliceMut::builder`] method.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CSliceMut;
+///
+/// let slice = &mut [0; 3];
+/// let ptr = slice.as_mut_ptr();
+/// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+/// let mut builder = cslice.builder();
+/// builder.push(1).unwrap();
+/// builder.push(2).unwrap();
+/// assert_eq!(builder.finish().as_ref(), [1, 2]);
+/// ```
+pub struct CBufBuilder<'a, T> {
+    inner: CSliceMut<'a, T>,
+    written: usize,
+}
+
+impl<'a, T> CBufBuilder<'a, T> {
+    pub(crate) fn new(inner: CSliceMut<'a, T>) -> CBufBuilder<'a, T> {
+        CBufBuilder { inner, written: 0 }
+    }
+
+    /// Writes `value` after the previously pushed elements. If the slice is
+    /// full, `value` is given back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0; 1];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// let mut builder = cslice.builder();
+    /// assert_eq!(builder.push(1), Ok(()));
+    /// assert_eq!(builder.push(2), Err(2));
+    /// ```
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.written >= self.inner.len() {
+            return Err(value);
+        }
+        unsafe {
+            ptr::write(self.inner.base.add(self.written), value);
+        }
+        self.written += 1;
+        Ok(())
+    }
+
+    /// Returns the number of elements pushed so far.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0; 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// let mut builder = cslice.builder();
+    /// builder.push(1).unwrap();
+    /// assert_eq!(builder.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.written
+    }
+
+    /// Returns whether no element has been pushed yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0; 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// assert!(cslice.builder().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.written == 0
+    }
+
+    /// Returns a [`CSliceMut`] over the pushed elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0; 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// let mut builder = cslice.builder();
+    /// builder.push(1).unwrap();
+    /// assert_eq!(builder.finish().len(), 1);
+    /// ```
+    pub fn finish(self) -> CSliceMut<'a, T> {
+        CSliceMut {
+            base: self.inner.base,
+            len: self.written,
+            _phantom: self.inner._phantom,
+        }
+    }
+}
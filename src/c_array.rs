@@ -0,0 +1,191 @@
+// Copyright 2012 The Rust Project Developers.
+// Copyright 2015 Guillaume Gomez
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::ops::{Index, IndexMut};
+
+/// The type representing a foreign mutable chunk of memory with a length known
+/// at compile-time.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CArray;
+///
+/// let slice = &mut [0, 1, 2];
+/// let ptr = slice.as_mut_ptr();
+/// let carray = unsafe { CArray::<_, 3>::new(ptr) };
+/// ```
+pub struct CArray<T, const N: usize> {
+    base: *mut T,
+}
+
+impl<T, const N: usize> CArray<T, N> {
+    /// Create a `CArray` from a raw pointer to a buffer of `N` elements.
+    ///
+    /// Panics if the given pointer is null. The returned array will not attempt
+    /// to deallocate the buffer when dropped.
+    ///
+    /// # Safety
+    ///
+    /// `base` must point to `N` initialized elements which remain valid for the
+    /// whole lifetime of the returned array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CArray;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let carray = unsafe { CArray::<_, 3>::new(ptr) };
+    /// ```
+    pub unsafe fn new(base: *mut T) -> CArray<T, N> {
+        assert!(!base.is_null());
+        CArray { base }
+    }
+
+    /// Retrieves an element at a given index, returning [`None`] if the requested
+    /// index is greater than the length of the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CArray;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let carray = unsafe { CArray::<_, 3>::new(ptr) };
+    /// assert_eq!(carray.get(1), Some(&1));
+    /// assert_eq!(carray.get(3), None);
+    /// ```
+    pub fn get(&self, ofs: usize) -> Option<&T> {
+        if ofs < N {
+            Some(unsafe { &*self.base.add(ofs) })
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves a mutable element at a given index, returning [`None`] if the
+    /// requested index is greater than the length of the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CArray;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut carray = unsafe { CArray::<_, 3>::new(ptr) };
+    /// if let Some(el) = carray.get_mut(1) {
+    ///     *el += 10;
+    /// }
+    /// assert_eq!(carray[1], 11);
+    /// ```
+    pub fn get_mut(&mut self, ofs: usize) -> Option<&mut T> {
+        if ofs < N {
+            Some(unsafe { &mut *self.base.add(ofs) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of items in this array, which is always `N`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CArray;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let carray = unsafe { CArray::<_, 3>::new(ptr) };
+    /// assert_eq!(carray.len(), 3);
+    /// ```
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns whether this array is empty, which is to say whether `N` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CArray;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let carray = unsafe { CArray::<_, 3>::new(ptr) };
+    /// assert!(!carray.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// View the stored data as an array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CArray;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let carray = unsafe { CArray::<_, 3>::new(ptr) };
+    /// assert_eq!(carray.as_array(), &[0, 1, 2]);
+    /// ```
+    pub fn as_array(&self) -> &[T; N] {
+        unsafe { &*(self.base as *const [T; N]) }
+    }
+
+    /// View the stored data as a mutable array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CArray;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut carray = unsafe { CArray::<_, 3>::new(ptr) };
+    /// carray.as_mut_array()[2] = 5;
+    /// assert_eq!(carray[2], 5);
+    /// ```
+    pub fn as_mut_array(&mut self) -> &mut [T; N] {
+        unsafe { &mut *(self.base as *mut [T; N]) }
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for CArray<T, N> {
+    /// View the stored data as a slice.
+    fn as_ref(&self) -> &[T] {
+        self.as_array()
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for CArray<T, N> {
+    /// View the stored data as a slice.
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_array()
+    }
+}
+
+impl<T, const N: usize> Index<usize> for CArray<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.as_array()[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for CArray<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.as_mut_array()[index]
+    }
+}
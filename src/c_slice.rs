@@ -7,10 +7,21 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::cmp;
+use std::convert::TryFrom;
+use std::ffi::{CString, NulError};
+use std::fmt::Write as _;
+use std::iter::{self, Copied, Sum};
 use std::marker::PhantomData;
-use std::ops::{Index, IndexMut};
+use std::mem::{self, MaybeUninit};
+use std::ops::{Bound, Index, IndexMut, RangeBounds};
+use std::ptr::{self, NonNull};
 use std::slice;
 
+use crate::channel::{self, ChannelView};
+use crate::checksum::{Checksum, Crc32};
+use crate::{BufferLayout, CBufBuilder, CReadCursor, CWriteCursor, RestoreGuard};
+
 /// Iterator over [`CSlice`].
 ///
 /// You can get it from the [`CSlice::iter`] method.
@@ -41,6 +52,96 @@ impl<'a, 'b, T> Iterator for CSliceIter<'a, 'b, T> {
             Some(unsafe { self.inner.get_unchecked(self.pos - 1) })
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.len() - self.pos;
+        (len, Some(len))
+    }
+}
+
+impl<'a, 'b, T> ExactSizeIterator for CSliceIter<'a, 'b, T> {}
+
+/// Reverse iterator over [`CSlice`].
+///
+/// You can get it from the [`CSlice::iter_rev`] method.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CSlice;
+///
+/// let slice = &[0, 1, 2];
+/// let ptr = slice.as_ptr();
+/// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+/// let iter = cslice.iter_rev();
+/// ```
+pub struct CSliceRevIter<'a, T> {
+    pub(crate) base: *const T,
+    pub(crate) pos: usize,
+    pub(crate) _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for CSliceRevIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos == 0 {
+            None
+        } else {
+            self.pos -= 1;
+            Some(unsafe { &*self.base.add(self.pos) })
+        }
+    }
+}
+
+/// Iterator over the sub-slices of a [`CSlice`] separated by elements matching
+/// a predicate.
+///
+/// You can get it from the [`CSlice::split`] method.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CSlice;
+///
+/// let slice = &[0, 1, 2];
+/// let ptr = slice.as_ptr();
+/// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+/// let iter = cslice.split(|x| *x == 1);
+/// ```
+pub struct CSliceSplit<'a, T, P> {
+    rest: &'a [T],
+    pred: P,
+    finished: bool,
+}
+
+impl<'a, T, P> Iterator for CSliceSplit<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = CSlice<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let part = match self.rest.iter().position(|x| (self.pred)(x)) {
+            Some(idx) => {
+                let part = &self.rest[..idx];
+                self.rest = &self.rest[idx + 1..];
+                part
+            }
+            None => {
+                self.finished = true;
+                self.rest
+            }
+        };
+        Some(CSlice {
+            base: part.as_ptr(),
+            len: part.len(),
+            _phantom: PhantomData,
+        })
+    }
 }
 
 /// The type representing an 'unsafe' non-mutable foreign chunk of memory.
@@ -66,6 +167,11 @@ impl<'a, T> CSlice<'a, T> {
     /// Panics if the given pointer is null. The returned slice will not attempt
     /// to deallocate the slice when dropped.
     ///
+    /// # Safety
+    ///
+    /// `base` must point to `len` initialized elements which remain valid for
+    /// the whole lifetime of the returned slice.
+    ///
     /// # Arguments
     ///
     /// * base - A raw pointer to a buffer
@@ -89,6 +195,31 @@ impl<'a, T> CSlice<'a, T> {
         }
     }
 
+    /// Create a `CSlice` from a non-null pointer to a buffer with a given length.
+    ///
+    /// # Safety
+    ///
+    /// `base` must point to `len` initialized elements which remain valid for
+    /// the whole lifetime of the returned slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    /// use std::ptr::NonNull;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = NonNull::new(slice.as_ptr() as *mut i32).unwrap();
+    /// let cslice = unsafe { CSlice::from_non_null(ptr, slice.len()) };
+    /// ```
+    pub unsafe fn from_non_null(base: NonNull<T>, len: usize) -> CSlice<'a, T> {
+        CSlice {
+            base: base.as_ptr(),
+            len,
+            _phantom: PhantomData,
+        }
+    }
+
     /// Retrieves an element at a given index, returning `None` if the requested
     /// index is greater than the length of the slice.
     ///
@@ -110,8 +241,49 @@ impl<'a, T> CSlice<'a, T> {
         }
     }
 
+    /// Retrieves the element at `ofs` modulo the length of the slice,
+    /// returning [`None`] only if the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.get_wrapping(4), Some(&1));
+    /// ```
+    pub fn get_wrapping(&self, ofs: usize) -> Option<&'a T> {
+        ofs.checked_rem(self.len)
+            .map(|ofs| unsafe { &*self.base.add(ofs) })
+    }
+
+    /// Retrieves an element at a given index, returning `default` if the
+    /// requested index is greater than the length of the slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.get_or(1, &10), &1);
+    /// assert_eq!(cslice.get_or(3, &10), &10);
+    /// ```
+    pub fn get_or<'b>(&'b self, ofs: usize, default: &'b T) -> &'b T {
+        self.as_ref().get(ofs).unwrap_or(default)
+    }
+
     /// Returns a reference to an element without doing any check.
     ///
+    /// # Safety
+    ///
+    /// `ofs` must be lower than the length of the slice. With the
+    /// `debug-bounds` feature, debug builds panic if it isn't.
+    ///
     /// # Example
     ///
     /// ```
@@ -125,6 +297,8 @@ impl<'a, T> CSlice<'a, T> {
     /// }
     /// ```
     pub unsafe fn get_unchecked(&'a self, ofs: usize) -> &'a T {
+        #[cfg(feature = "debug-bounds")]
+        debug_assert!(ofs < self.len);
         &*self.base.add(ofs)
     }
 
@@ -160,184 +334,1880 @@ impl<'a, T> CSlice<'a, T> {
         self.len() == 0
     }
 
-    /// Returns an iterator over `CSlice`.
+    /// Returns a description of the memory used by the slice.
     ///
     /// # Example
     ///
     /// ```
     /// use c_vec::CSlice;
     ///
-    /// let slice = &[0, 1, 2];
+    /// let slice = &[0u32, 1, 2];
     /// let ptr = slice.as_ptr();
     /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
-    /// for elem in cslice.iter() {
-    ///     println!("=> {}", elem);
-    /// }
+    /// let layout = cslice.layout();
+    /// assert_eq!(layout.byte_len, 12);
     /// ```
-    pub fn iter<'b>(&'b self) -> CSliceIter<'a, 'b, T> {
-        CSliceIter {
-            inner: self,
-            pos: 0,
-        }
-    }
-}
-
-impl<'a, T> AsRef<[T]> for CSlice<'a, T> {
-    /// View the stored data as a slice.
-    fn as_ref(&self) -> &[T] {
-        unsafe { slice::from_raw_parts(self.base as *const T, self.len) }
-    }
-}
-
-impl<'a, T> Index<usize> for CSlice<'a, T> {
-    type Output = T;
-
-    fn index(&self, index: usize) -> &T {
-        assert!(index < self.len);
-        unsafe { &*self.base.add(index) }
+    pub fn layout(&self) -> BufferLayout {
+        BufferLayout::new(self.base, self.len)
     }
-}
 
-impl<'a, T: Clone> Into<Vec<T>> for CSlice<'a, T> {
-    fn into(self: CSlice<'a, T>) -> Vec<T> {
-        let mut v = Vec::with_capacity(self.len);
-        v.extend_from_slice(self.as_ref());
-        v
+    /// Returns `true` if the whole slice lies inside the memory region of
+    /// `region_len` bytes starting at `region_start`, which is useful to check
+    /// that a foreign buffer really points into an expected arena.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let region = &mut [0u32; 8];
+    /// let cslice = unsafe { CSlice::new(region.as_mut_ptr().add(2), 3) };
+    /// assert!(cslice.is_within(region.as_ptr() as *const u8, 32));
+    /// assert!(!cslice.is_within(region.as_ptr() as *const u8, 16));
+    /// ```
+    pub fn is_within(&self, region_start: *const u8, region_len: usize) -> bool {
+        self.layout().is_within(region_start, region_len)
     }
-}
-
-/// Iterator over [`CSliceMut`].
-///
-/// You can get it from the [`CSliceMut::iter`] method.
-///
-/// # Example
-///
-/// ```
-/// use c_vec::CSliceMut;
-///
-/// let slice = &mut [0, 1, 2];
-/// let ptr = slice.as_mut_ptr();
-/// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
-/// let iter = cslice.iter();
-/// ```
-pub struct CSliceMutIter<'a, 'b, T> {
-    inner: &'b CSliceMut<'a, T>,
-    pos: usize,
-}
-
-impl<'a, 'b, T> Iterator for CSliceMutIter<'a, 'b, T> {
-    type Item = &'b T;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.pos >= self.inner.len() {
-            None
-        } else {
-            self.pos += 1;
-            Some(unsafe { self.inner.get_unchecked(self.pos - 1) })
+    /// Removes the first element from the slice and returns it, or returns
+    /// [`None`] if the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let mut cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.take_first(), Some(&0));
+    /// assert_eq!(cslice.as_ref(), [1, 2]);
+    /// ```
+    pub fn take_first(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
         }
+        let first = self.base;
+        self.base = unsafe { self.base.add(1) };
+        self.len -= 1;
+        Some(unsafe { &*first })
     }
-}
-
-/// Mutable iterator over [`CSliceMut`].
-///
-/// You can get it from the [`CSliceMut::iter_mut`] method.
-///
-/// # Example
-///
-/// ```
-/// use c_vec::CSliceMut;
-///
-/// let slice = &mut [0, 1, 2];
-/// let ptr = slice.as_mut_ptr();
-/// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
-/// let iter = cslice.iter_mut();
-/// ```
-pub struct CSliceMutIterMut<'a, 'b, T> {
-    inner: &'b mut CSliceMut<'a, T>,
-    pos: usize,
-}
 
-impl<'a, 'b, T> Iterator for CSliceMutIterMut<'a, 'b, T> {
-    type Item = &'b mut T;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.pos >= self.inner.len() {
-            None
-        } else {
-            self.pos += 1;
-            Some(unsafe { &mut *self.inner.base.add(self.pos - 1) })
+    /// Removes the last element from the slice and returns it, or returns
+    /// [`None`] if the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let mut cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.take_last(), Some(&2));
+    /// assert_eq!(cslice.as_ref(), [0, 1]);
+    /// ```
+    pub fn take_last(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
         }
+        self.len -= 1;
+        Some(unsafe { &*self.base.add(self.len) })
     }
-}
-
-/// The type representing an 'unsafe' mutable foreign chunk of memory.
-///
-/// # Example
-///
-/// ```
-/// use c_vec::CSliceMut;
-///
-/// let slice = &mut [0, 1, 2];
-/// let ptr = slice.as_mut_ptr();
-/// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
-/// ```
-pub struct CSliceMut<'a, T> {
-    pub(crate) base: *mut T,
-    pub(crate) len: usize,
-    pub(crate) _phantom: PhantomData<&'a ()>,
-}
 
-impl<'a, T> CSliceMut<'a, T> {
-    /// Create a `CSlice` from a raw pointer to a buffer with a given length.
-    ///
-    /// Panics if the given pointer is null. The returned slice will not attempt
-    /// to deallocate the slice when dropped.
-    ///
-    /// # Arguments
+    /// Returns the index of the first element which differs between the two
+    /// slices, or [`None`] if they are equal.
     ///
-    /// * base - A raw pointer to a buffer
-    /// * len - The number of elements in the buffer
+    /// If one slice is a prefix of the other, the length of the shorter one is
+    /// returned.
     ///
     /// # Example
     ///
     /// ```
-    /// use c_vec::CSliceMut;
+    /// use c_vec::CSlice;
     ///
-    /// let slice = &mut [0, 1, 2];
-    /// let ptr = slice.as_mut_ptr();
-    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// let (a, b) = (&[0, 1, 2], &[0, 1, 3]);
+    /// let a = unsafe { CSlice::new(a.as_ptr(), a.len()) };
+    /// let b = unsafe { CSlice::new(b.as_ptr(), b.len()) };
+    /// assert_eq!(a.diff_index(&b), Some(2));
+    /// assert_eq!(a.diff_index(&a), None);
     /// ```
-    pub unsafe fn new(base: *mut T, len: usize) -> CSliceMut<'a, T> {
-        assert!(!base.is_null());
-        Self {
-            base,
-            len,
-            _phantom: PhantomData,
+    pub fn diff_index(&self, other: &CSlice<T>) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        let (a, b) = (self.as_ref(), other.as_ref());
+        match a.iter().zip(b).position(|(x, y)| x != y) {
+            Some(pos) => Some(pos),
+            None if a.len() != b.len() => Some(cmp::min(a.len(), b.len())),
+            None => None,
         }
     }
 
-    /// Retrieves an element at a given index, returning `None` if the requested
-    /// index is greater than the length of the slice.
+    /// Returns whether the first `n` elements of both slices are equal.
+    ///
+    /// Panics if any of the slices has less than `n` elements.
     ///
     /// # Example
     ///
     /// ```
-    /// use c_vec::CSliceMut;
+    /// use c_vec::CSlice;
     ///
-    /// let slice = &mut [0, 1, 2];
-    /// let ptr = slice.as_mut_ptr();
-    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
-    /// assert_eq!(cslice.get(1), slice.get(1));
+    /// let (a, b) = (&[0, 1, 2], &[0, 1, 3]);
+    /// let a = unsafe { CSlice::new(a.as_ptr(), a.len()) };
+    /// let b = unsafe { CSlice::new(b.as_ptr(), b.len()) };
+    /// assert!(a.eq_prefix(&b, 2));
+    /// assert!(!a.eq_prefix(&b, 3));
     /// ```
-    pub fn get(&self, ofs: usize) -> Option<&T> {
-        if ofs < self.len {
-            Some(unsafe { &*self.base.add(ofs) })
-        } else {
-            None
-        }
+    pub fn eq_prefix(&self, other: &CSlice<T>, n: usize) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_ref()[..n] == other.as_ref()[..n]
     }
 
-    /// Returns a reference to an element without doing any check.
+    /// Returns whether the slice contains the same elements as `iter`, in the
+    /// same order, without collecting the iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert!(cslice.eq_iter(0..3));
+    /// assert!(!cslice.eq_iter(0..2));
+    /// ```
+    pub fn eq_iter<I>(&self, iter: I) -> bool
+    where
+        I: IntoIterator<Item = T>,
+        T: PartialEq,
+    {
+        let mut iter = iter.into_iter();
+        self.as_slice()
+            .iter()
+            .all(|elem| iter.next().is_some_and(|other| *elem == other))
+            && iter.next().is_none()
+    }
+
+    /// Returns the index and the values of each position where the slice and
+    /// `other` differ, up to the length of the shorter one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let other = &[0, 3];
+    /// let other = unsafe { CSlice::new(other.as_ptr(), other.len()) };
+    /// assert_eq!(cslice.diff(&other), [(1, 1, 3)]);
+    /// ```
+    pub fn diff(&self, other: &CSlice<'_, T>) -> Vec<(usize, T, T)>
+    where
+        T: PartialEq + Clone,
+    {
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .map(|(i, (a, b))| (i, a.clone(), b.clone()))
+            .collect()
+    }
+
+    /// Calls `f` on each consecutive sub-slice of `chunk` elements. The last
+    /// sub-slice is shorter if the length isn't a multiple of `chunk`.
+    ///
+    /// Panics if `chunk` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// cslice.for_each_chunk(2, |chunk| println!("=> {:?}", chunk.as_ref()));
+    /// ```
+    pub fn for_each_chunk<F>(&self, chunk: usize, mut f: F)
+    where
+        F: FnMut(CSlice<T>),
+    {
+        assert!(chunk != 0);
+        let mut pos = 0;
+        while pos < self.len {
+            let len = cmp::min(chunk, self.len - pos);
+            f(CSlice {
+                base: unsafe { self.base.add(pos) },
+                len,
+                _phantom: PhantomData,
+            });
+            pos += len;
+        }
+    }
+
+    /// Calls `f` on each element along with its left and right neighbors
+    /// ([`None`] at the edges of the slice) and collects the results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[1, 2, 3];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let sums = cslice.map_neighbors(|l, x, r| l.unwrap_or(&0) + x + r.unwrap_or(&0));
+    /// assert_eq!(sums, [3, 6, 5]);
+    /// ```
+    pub fn map_neighbors<F>(&self, mut f: F) -> Vec<T>
+    where
+        F: FnMut(Option<&T>, &T, Option<&T>) -> T,
+    {
+        let data = self.as_ref();
+        let mut v = Vec::with_capacity(data.len());
+        for pos in 0..data.len() {
+            let left = if pos > 0 { data.get(pos - 1) } else { None };
+            v.push(f(left, &data[pos], data.get(pos + 1)));
+        }
+        v
+    }
+
+    /// Returns the number of elements of the slice matching `pred`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[1, 2, 3];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.count(|x| x % 2 == 1), 2);
+    /// ```
+    pub fn count<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_ref().iter().filter(|x| pred(x)).count()
+    }
+
+    /// Returns whether all the elements of the slice match `pred`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[1, 2, 3];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert!(cslice.all(|x| *x > 0));
+    /// ```
+    pub fn all<P>(&self, pred: P) -> bool
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_ref().iter().all(pred)
+    }
+
+    /// Returns whether any element of the slice matches `pred`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[1, 2, 3];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert!(cslice.any(|x| *x == 2));
+    /// ```
+    pub fn any<P>(&self, pred: P) -> bool
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_ref().iter().any(pred)
+    }
+
+    /// Returns an iterator over copies of consecutive groups of `N` elements.
+    /// If the length isn't a multiple of `N`, the remaining elements are
+    /// skipped.
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2, 3, 4];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let mut iter = cslice.array_chunks::<2>();
+    /// assert_eq!(iter.next(), Some([0, 1]));
+    /// assert_eq!(iter.next(), Some([2, 3]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn array_chunks<const N: usize>(&self) -> impl Iterator<Item = [T; N]> + 'a
+    where
+        T: Copy + 'a,
+    {
+        self.as_slice()
+            .chunks_exact(N)
+            .map(|chunk| <[T; N]>::try_from(chunk).unwrap())
+    }
+
+    /// Returns an iterator over the overlapping pairs of consecutive elements:
+    /// `(&self[0], &self[1])`, `(&self[1], &self[2])`, etc. Yields nothing if
+    /// the slice has less than 2 elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let mut iter = cslice.pairwise();
+    /// assert_eq!(iter.next(), Some((&0, &1)));
+    /// assert_eq!(iter.next(), Some((&1, &2)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn pairwise(&self) -> impl Iterator<Item = (&'a T, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        self.as_slice().windows(2).map(|pair| (&pair[0], &pair[1]))
+    }
+
+    /// Returns an iterator over the indices of the elements matching `pred`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2, 3];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let indices = cslice.match_indices(|x| x % 2 == 1).collect::<Vec<_>>();
+    /// assert_eq!(indices, [1, 3]);
+    /// ```
+    pub fn match_indices<P>(&self, mut pred: P) -> impl Iterator<Item = usize> + 'a
+    where
+        P: FnMut(&T) -> bool + 'a,
+        T: 'a,
+    {
+        self.as_slice()
+            .iter()
+            .enumerate()
+            .filter_map(move |(idx, elem)| if pred(elem) { Some(idx) } else { None })
+    }
+
+    /// Returns an iterator over the indices of the elements starting in a
+    /// different memory page than the previous element, based on their
+    /// address. The first element is always included.
+    ///
+    /// Panics if `page_size` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0u8; 16];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.page_offsets(4096).next(), Some(0));
+    /// ```
+    pub fn page_offsets(&self, page_size: usize) -> impl Iterator<Item = usize> {
+        assert!(page_size != 0);
+        let base = self.base as usize;
+        let size = mem::size_of::<T>();
+        (0..self.len).filter(move |&idx| {
+            idx == 0 || (base + idx * size) / page_size != (base + (idx - 1) * size) / page_size
+        })
+    }
+
+    /// Returns the maximum element of the slice, or [`None`] if it's empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[3, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.max(), Some(&3));
+    /// ```
+    pub fn max(&self) -> Option<&'a T>
+    where
+        T: Ord,
+    {
+        self.as_slice().iter().max()
+    }
+
+    /// Returns the minimum element of the slice, or [`None`] if it's empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[3, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.min(), Some(&1));
+    /// ```
+    pub fn min(&self) -> Option<&'a T>
+    where
+        T: Ord,
+    {
+        self.as_slice().iter().min()
+    }
+
+    /// Returns the sum of the elements of the slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[3, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.sum(), 6);
+    /// ```
+    pub fn sum(&self) -> T
+    where
+        T: Sum + Copy,
+    {
+        self.as_ref().iter().copied().sum()
+    }
+
+    /// Returns a `CSlice` over the first `n` elements, or over the whole slice
+    /// if it has less than `n` elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.prefix(2).as_ref(), [0, 1]);
+    /// assert_eq!(cslice.prefix(10).as_ref(), [0, 1, 2]);
+    /// ```
+    pub fn prefix(&self, n: usize) -> CSlice<'a, T> {
+        CSlice {
+            base: self.base,
+            len: cmp::min(n, self.len),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a `CSlice` over the last `n` elements, or over the whole slice
+    /// if it has less than `n` elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.suffix(2).as_ref(), [1, 2]);
+    /// assert_eq!(cslice.suffix(10).as_ref(), [0, 1, 2]);
+    /// ```
+    pub fn suffix(&self, n: usize) -> CSlice<'a, T> {
+        let len = cmp::min(n, self.len);
+        CSlice {
+            base: unsafe { self.base.add(self.len - len) },
+            len,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the parts of the slice before and after `range`, excluding the
+    /// range itself.
+    ///
+    /// Panics if the range is out of bounds or if its start is after its end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2, 3];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let (before, after) = cslice.without(1..3);
+    /// assert_eq!(before.as_ref(), [0]);
+    /// assert_eq!(after.as_ref(), [3]);
+    /// ```
+    pub fn without<R: RangeBounds<usize>>(&self, range: R) -> (CSlice<'a, T>, CSlice<'a, T>) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("range start overflow"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("range end overflow"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len,
+        };
+        assert!(start <= end && end <= self.len);
+        (
+            CSlice {
+                base: self.base,
+                len: start,
+                _phantom: PhantomData,
+            },
+            CSlice {
+                base: unsafe { self.base.add(end) },
+                len: self.len - end,
+                _phantom: PhantomData,
+            },
+        )
+    }
+
+    /// Returns a [`CSlice`] over the elements from `start` to `end` (excluded).
+    /// Both bounds are clamped to the length of the slice and an empty slice is
+    /// returned if `start` is after `end`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.saturating_slice(1, 10).as_ref(), [1, 2]);
+    /// assert!(cslice.saturating_slice(2, 1).is_empty());
+    /// ```
+    pub fn saturating_slice(&self, start: usize, end: usize) -> CSlice<'a, T> {
+        let end = cmp::min(end, self.len);
+        let start = cmp::min(start, end);
+        CSlice {
+            base: unsafe { self.base.add(start) },
+            len: end - start,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Splits the slice into two halves of the same length, or returns [`None`]
+    /// if its length is odd.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2, 3];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let (left, right) = cslice.split_even().unwrap();
+    /// assert_eq!(left.as_ref(), [0, 1]);
+    /// assert_eq!(right.as_ref(), [2, 3]);
+    /// ```
+    pub fn split_even(&self) -> Option<(CSlice<'a, T>, CSlice<'a, T>)> {
+        if !self.len.is_multiple_of(2) {
+            return None;
+        }
+        let half = self.len / 2;
+        Some((self.prefix(half), self.suffix(half)))
+    }
+
+    /// Returns a [`CSlice`] over the leading elements satisfying `pred`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 2, 3, 4];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.take_while_view(|x| x % 2 == 0).as_ref(), [0, 2]);
+    /// ```
+    pub fn take_while_view<P: FnMut(&T) -> bool>(&self, mut pred: P) -> CSlice<'a, T> {
+        let n = self.as_slice().iter().take_while(|x| pred(x)).count();
+        self.prefix(n)
+    }
+
+    /// Returns a [`CSlice`] over the elements remaining after the leading ones
+    /// satisfying `pred`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 2, 3, 4];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.skip_while_view(|x| x % 2 == 0).as_ref(), [3, 4]);
+    /// ```
+    pub fn skip_while_view<P: FnMut(&T) -> bool>(&self, pred: P) -> CSlice<'a, T> {
+        let n = self.take_while_view(pred).len();
+        self.saturating_slice(n, self.len)
+    }
+
+    /// Splits the slice into a prefix, a middle part correctly aligned for `U`
+    /// and a suffix, like [`slice::align_to`].
+    ///
+    /// # Safety
+    ///
+    /// Same as [`slice::align_to`]: any bit pattern of the middle part must be
+    /// a valid `U`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0u8; 9];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let (head, middle, tail) = unsafe { cslice.align_to::<u16>() };
+    /// assert_eq!(head.len() + middle.len() * 2 + tail.len(), 9);
+    /// ```
+    pub unsafe fn align_to<U>(&self) -> (CSlice<'a, T>, CSlice<'a, U>, CSlice<'a, T>) {
+        let (head, middle, tail) = self.as_slice().align_to::<U>();
+        (
+            CSlice::new(head.as_ptr(), head.len()),
+            CSlice::new(middle.as_ptr(), middle.len()),
+            CSlice::new(tail.as_ptr(), tail.len()),
+        )
+    }
+
+    /// Returns the index of `elem` if it points to an element of the slice,
+    /// or [`None`] otherwise (or if `T` is zero-sized).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.offset_of(&cslice[2]), Some(2));
+    /// assert_eq!(cslice.offset_of(&0), None);
+    /// ```
+    pub fn offset_of(&self, elem: &T) -> Option<usize> {
+        let size = mem::size_of::<T>();
+        let diff = (elem as *const T as usize).checked_sub(self.base as usize)?;
+        if size == 0 || !diff.is_multiple_of(size) || diff / size >= self.len {
+            return None;
+        }
+        Some(diff / size)
+    }
+
+    /// Copies the slice into a new `Vec` of length `total`, filling the
+    /// remaining elements with `pad`.
+    ///
+    /// Panics if `total` is smaller than the length of the slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.to_vec_padded(5, 9), [0, 1, 2, 9, 9]);
+    /// ```
+    pub fn to_vec_padded(&self, total: usize, pad: T) -> Vec<T>
+    where
+        T: Clone,
+    {
+        assert!(total >= self.len);
+        let mut ret = Vec::with_capacity(total);
+        ret.extend_from_slice(self.as_slice());
+        ret.resize(total, pad);
+        ret
+    }
+
+    /// Copies the slice into a new `Vec`, in reverse order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.to_vec_reversed(), [2, 1, 0]);
+    /// ```
+    pub fn to_vec_reversed(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.as_slice().iter().rev().cloned().collect()
+    }
+
+    /// Copies the slice into a new `Vec`, skipping consecutive duplicates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 0, 1, 0];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.to_vec_dedup(), [0, 1, 0]);
+    /// ```
+    pub fn to_vec_dedup(&self) -> Vec<T>
+    where
+        T: PartialEq + Clone,
+    {
+        let mut ret: Vec<T> = Vec::with_capacity(self.len);
+        for elem in self.as_slice() {
+            if ret.last() != Some(elem) {
+                ret.push(elem.clone());
+            }
+        }
+        ret
+    }
+
+    /// Copies the slice into a new sorted `Vec` without duplicates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 0, 1, 0];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.to_vec_dedup_sorted(), [0, 1]);
+    /// ```
+    pub fn to_vec_dedup_sorted(&self) -> Vec<T>
+    where
+        T: Ord + Clone,
+    {
+        let mut ret = self.as_slice().to_vec();
+        ret.sort_unstable();
+        ret.dedup();
+        ret
+    }
+
+    /// Returns the runs of equal consecutive elements of the slice, along with
+    /// their length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 0, 1];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.rle(), [(0, 2), (1, 1)]);
+    /// ```
+    pub fn rle(&self) -> Vec<(T, usize)>
+    where
+        T: PartialEq + Clone,
+    {
+        let mut ret: Vec<(T, usize)> = Vec::new();
+        for elem in self.as_slice() {
+            match ret.last_mut() {
+                Some((last, count)) if last == elem => *count += 1,
+                _ => ret.push((elem.clone(), 1)),
+            }
+        }
+        ret
+    }
+
+    /// Replaces the content of `target` with a copy of the slice, reusing its
+    /// allocation when possible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let mut v = vec![5; 10];
+    /// cslice.clone_into(&mut v);
+    /// assert_eq!(v, [0, 1, 2]);
+    /// ```
+    pub fn clone_into(&self, target: &mut Vec<T>)
+    where
+        T: Clone,
+    {
+        target.clear();
+        target.extend_from_slice(self.as_slice());
+    }
+
+    /// Copies the slice into `dst` and returns it, now initialized.
+    ///
+    /// Panics if `dst` doesn't have the same length as the slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let mut dst = [MaybeUninit::uninit(); 3];
+    /// assert_eq!(cslice.copy_to_uninit(&mut dst), [0, 1, 2]);
+    /// ```
+    pub fn copy_to_uninit<'d>(&self, dst: &'d mut [MaybeUninit<T>]) -> &'d mut [T]
+    where
+        T: Copy,
+    {
+        assert_eq!(dst.len(), self.len);
+        unsafe {
+            ptr::copy_nonoverlapping(self.base, dst.as_mut_ptr() as *mut T, self.len);
+            slice::from_raw_parts_mut(dst.as_mut_ptr() as *mut T, self.len)
+        }
+    }
+
+    /// Copies the elements of the slice into two new `Vec`s: the first one
+    /// contains the elements for which `pred` returns `true`, the second one
+    /// the others.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.partition_copy(|x| *x > 0), (vec![1, 2], vec![0]));
+    /// ```
+    pub fn partition_copy<P>(&self, pred: P) -> (Vec<T>, Vec<T>)
+    where
+        P: FnMut(&T) -> bool,
+        T: Clone,
+    {
+        self.as_slice().iter().cloned().partition(pred)
+    }
+
+    /// Folds every element of the slice into an accumulator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[1, 2, 3];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.fold(1, |acc, x| acc * x), 6);
+    /// ```
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        self.as_ref().iter().fold(init, f)
+    }
+
+    /// Calls `f` on each element of the slice, stopping at the first error,
+    /// which is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let res = cslice.try_for_each(|x| if *x < 2 { Ok(()) } else { Err(*x) });
+    /// assert_eq!(res, Err(2));
+    /// ```
+    pub fn try_for_each<E, F>(&self, f: F) -> Result<(), E>
+    where
+        F: FnMut(&T) -> Result<(), E>,
+    {
+        self.as_slice().iter().try_for_each(f)
+    }
+
+    /// Returns a [`Vec`] alternating the elements of both slices, starting with
+    /// the first element of this one.
+    ///
+    /// Panics if the slices don't have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let (a, b) = (&[0, 2], &[1, 3]);
+    /// let a = unsafe { CSlice::new(a.as_ptr(), a.len()) };
+    /// let b = unsafe { CSlice::new(b.as_ptr(), b.len()) };
+    /// assert_eq!(a.interleave(&b), [0, 1, 2, 3]);
+    /// ```
+    pub fn interleave(&self, other: &CSlice<T>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        assert_eq!(self.len, other.len);
+        let mut v = Vec::with_capacity(self.len * 2);
+        for (a, b) in self.as_ref().iter().zip(other.as_ref()) {
+            v.push(a.clone());
+            v.push(b.clone());
+        }
+        v
+    }
+
+    /// Splits the elements of the slice into two [`Vec`]s: the first one gets
+    /// the elements at even indices and the second one the elements at odd
+    /// indices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2, 3, 4];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.deinterleave(), (vec![0, 2, 4], vec![1, 3]));
+    /// ```
+    pub fn deinterleave(&self) -> (Vec<T>, Vec<T>)
+    where
+        T: Clone,
+    {
+        let mut even = Vec::with_capacity(self.len - self.len / 2);
+        let mut odd = Vec::with_capacity(self.len / 2);
+        for (pos, elem) in self.as_ref().iter().enumerate() {
+            if pos % 2 == 0 {
+                even.push(elem.clone());
+            } else {
+                odd.push(elem.clone());
+            }
+        }
+        (even, odd)
+    }
+
+    /// Returns whether all the bytes of the slice are zero.
+    ///
+    /// # Safety
+    ///
+    /// `T` must not contain padding bytes, as they may be uninitialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0u32; 3];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert!(unsafe { cslice.is_zeroed() });
+    /// ```
+    pub unsafe fn is_zeroed(&self) -> bool
+    where
+        T: Copy,
+    {
+        let bytes = slice::from_raw_parts(self.base as *const u8, mem::size_of_val(self.as_ref()));
+        let (head, words, tail) = bytes.align_to::<usize>();
+        head.iter().all(|&b| b == 0)
+            && words.iter().all(|&w| w == 0)
+            && tail.iter().all(|&b| b == 0)
+    }
+
+    /// Returns an iterator over `CSlice`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// for elem in cslice.iter() {
+    ///     println!("=> {}", elem);
+    /// }
+    /// ```
+    pub fn iter<'b>(&'b self) -> CSliceIter<'a, 'b, T> {
+        CSliceIter {
+            inner: self,
+            pos: 0,
+        }
+    }
+
+    /// Returns a [`slice::Iter`] over the `CSlice` data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.std_iter().rev().collect::<Vec<_>>(), [&2, &1, &0]);
+    /// ```
+    pub fn std_iter(&self) -> slice::Iter<'a, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over copies of the `CSlice` elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.iter_copied().collect::<Vec<_>>(), [0, 1, 2]);
+    /// ```
+    pub fn iter_copied<'b>(&'b self) -> Copied<CSliceIter<'a, 'b, T>>
+    where
+        T: Copy,
+    {
+        self.iter().copied()
+    }
+
+    /// Returns an iterator over `CSlice` going from the last element to the
+    /// first one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let mut iter = cslice.iter_rev();
+    /// assert_eq!(iter.next(), Some(&2));
+    /// ```
+    pub fn iter_rev(&self) -> CSliceRevIter<'a, T> {
+        CSliceRevIter {
+            base: self.base,
+            pos: self.len,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the sub-slices separated by elements matching
+    /// `pred`. The separators aren't included in the sub-slices, and consecutive
+    /// separators (or one at either end) produce empty sub-slices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0, 1, 2];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let mut iter = cslice.split(|x| *x == 1);
+    /// assert_eq!(iter.next().unwrap().as_ref(), [0]);
+    /// assert_eq!(iter.next().unwrap().as_ref(), [2]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn split<P>(&self, pred: P) -> CSliceSplit<'a, T, P>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        CSliceSplit {
+            rest: self.as_slice(),
+            pred,
+            finished: false,
+        }
+    }
+}
+
+macro_rules! read_int {
+    ($($name:ident => $ty:ident::$from:ident, $endian:literal, $bytes:literal;)*) => {
+        $(
+            #[doc = concat!(
+                "Reads a `", stringify!($ty), "` stored in ", $endian,
+                " endian at the given byte offset, returning [`None`] if the slice is too short.",
+            )]
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// use c_vec::CSlice;
+            ///
+            #[doc = concat!("let slice = &", $bytes, ";")]
+            /// let ptr = slice.as_ptr();
+            /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+            #[doc = concat!("assert_eq!(cslice.", stringify!($name), "(1), Some(1));")]
+            #[doc = concat!("assert_eq!(cslice.", stringify!($name), "(2), None);")]
+            /// ```
+            pub fn $name(&self, ofs: usize) -> Option<$ty> {
+                let end = ofs.checked_add(mem::size_of::<$ty>())?;
+                let bytes = self.as_slice().get(ofs..end)?;
+                Some($ty::$from(TryFrom::try_from(bytes).unwrap()))
+            }
+        )*
+    };
+}
+
+impl<'a> CSlice<'a, u8> {
+    read_int! {
+        read_u16_le => u16::from_le_bytes, "little", "[0u8, 1, 0]";
+        read_u16_be => u16::from_be_bytes, "big", "[0u8, 0, 1]";
+        read_u32_le => u32::from_le_bytes, "little", "[0u8, 1, 0, 0, 0]";
+        read_u32_be => u32::from_be_bytes, "big", "[0u8, 0, 0, 0, 1]";
+        read_u64_le => u64::from_le_bytes, "little", "[0u8, 1, 0, 0, 0, 0, 0, 0, 0]";
+        read_u64_be => u64::from_be_bytes, "big", "[0u8, 0, 0, 0, 0, 0, 0, 0, 1]";
+    }
+
+    /// Create a `CSlice` over a NUL-terminated C string. The terminating NUL
+    /// byte isn't part of the returned slice.
+    ///
+    /// Panics if the given pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must point to a NUL-terminated string which remains valid for
+    /// the whole lifetime of the returned slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let s = b"hello\0";
+    /// let cslice = unsafe { CSlice::from_c_str(s.as_ptr()) };
+    /// assert_eq!(cslice.as_ref(), b"hello");
+    /// ```
+    #[cfg(feature = "libc")]
+    pub unsafe fn from_c_str(base: *const u8) -> CSlice<'a, u8> {
+        assert!(!base.is_null());
+        CSlice::new(base, libc::strlen(base as *const libc::c_char))
+    }
+
+    /// Returns a [`CReadCursor`] reading bytes from this slice, starting at
+    /// its beginning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    /// use std::io::Read;
+    ///
+    /// let slice = b"hello";
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let mut s = String::new();
+    /// cslice.reader().read_to_string(&mut s).unwrap();
+    /// assert_eq!(s, "hello");
+    /// ```
+    pub fn reader(&self) -> CReadCursor<'a> {
+        CReadCursor::new(CSlice {
+            base: self.base,
+            len: self.len,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Computes the CRC-32 (as used by zlib or PNG) of the bytes of the slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = b"123456789";
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.checksum_u32(), 0xCBF43926);
+    /// ```
+    pub fn checksum_u32(&self) -> u32 {
+        self.checksum(Crc32::default()) as u32
+    }
+
+    /// Computes the checksum of the bytes of the slice with the given
+    /// algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::checksum::Additive;
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[1u8, 2, 3];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.checksum(Additive::default()), 6);
+    /// ```
+    pub fn checksum<C: Checksum>(&self, mut algo: C) -> u64 {
+        algo.update(self.as_slice());
+        algo.finalize()
+    }
+
+    /// Computes the 64-bit FNV-1a hash of the bytes of the slice. Unlike
+    /// [`Hash`](std::hash::Hash), the result is the same on every platform.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = b"hello";
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.fnv1a_hash(), 0xa430d84680aabd0b);
+    /// ```
+    pub fn fnv1a_hash(&self) -> u64 {
+        self.fold(0xcbf29ce484222325, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        })
+    }
+
+    /// Splits the slice into a prefix, a middle part of `usize` words correctly
+    /// aligned and a suffix, to process most of the bytes a word at a time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = &[0u8; 64];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let (head, words, tail) = cslice.words();
+    /// assert_eq!(head.len() + words.len() * std::mem::size_of::<usize>() + tail.len(), 64);
+    /// ```
+    pub fn words(&self) -> (CSlice<'a, u8>, CSlice<'a, usize>, CSlice<'a, u8>) {
+        // Any bit pattern is a valid `usize`.
+        unsafe { self.align_to() }
+    }
+
+    /// Splits the slice into a header of type `H` read from its first bytes
+    /// and a [`CSlice`] over the remaining bytes.
+    ///
+    /// Returns [`None`] if the slice is shorter than `H` or if its start
+    /// isn't correctly aligned for `H`.
+    ///
+    /// # Safety
+    ///
+    /// The first `size_of::<H>()` bytes must be a valid `H`, which is usually a
+    /// `#[repr(C)]` type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// #[repr(C)]
+    /// struct Header {
+    ///     magic: [u8; 3],
+    ///     version: u8,
+    /// }
+    ///
+    /// let slice = b"CV\x002payload";
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let (header, body) = unsafe { cslice.split_header::<Header>().unwrap() };
+    /// assert_eq!(&header.magic, b"CV\x00");
+    /// assert_eq!(header.version, b'2');
+    /// assert_eq!(body.as_ref(), b"payload");
+    /// ```
+    pub unsafe fn split_header<H>(&self) -> Option<(&'a H, CSlice<'a, u8>)> {
+        if self.len < mem::size_of::<H>()
+            || !(self.base as usize).is_multiple_of(mem::align_of::<H>())
+        {
+            return None;
+        }
+        Some((
+            &*(self.base as *const H),
+            self.saturating_slice(mem::size_of::<H>(), self.len),
+        ))
+    }
+
+    /// Copies the bytes of the slice into a new [`CString`].
+    ///
+    /// Returns an error if the slice contains a nul byte.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = b"hello";
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.to_cstring().unwrap().as_bytes(), b"hello");
+    /// ```
+    pub fn to_cstring(&self) -> Result<CString, NulError> {
+        CString::new(self.as_slice())
+    }
+
+    /// Returns an `xxd`-like dump of the slice: each line contains the offset
+    /// of its first byte, up to 16 bytes in hexadecimal and their ASCII
+    /// representation, non-printable characters being replaced with `.`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    ///
+    /// let slice = b"Hello!";
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// assert_eq!(
+    ///     cslice.hexdump(),
+    ///     "00000000: 4865 6c6c 6f21                           Hello!\n",
+    /// );
+    /// ```
+    pub fn hexdump(&self) -> String {
+        let mut out = String::new();
+        for (i, line) in self.as_slice().chunks(16).enumerate() {
+            let _ = write!(out, "{:08x}:", i * 16);
+            for (j, byte) in line.iter().enumerate() {
+                if j % 2 == 0 {
+                    out.push(' ');
+                }
+                let _ = write!(out, "{:02x}", byte);
+            }
+            // Pads the hexadecimal part of the last line so the ASCII parts are aligned.
+            let missing = 16 - line.len();
+            out.extend(iter::repeat_n(' ', missing * 2 + missing / 2));
+            out.push_str("  ");
+            out.extend(line.iter().map(|&b| {
+                if b == b' ' || b.is_ascii_graphic() {
+                    b as char
+                } else {
+                    '.'
+                }
+            }));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<'a, T> CSlice<'a, T> {
+    pub(crate) fn as_slice(&self) -> &'a [T] {
+        unsafe { slice::from_raw_parts(self.base, self.len) }
+    }
+}
+
+impl<'a, T> AsRef<[T]> for CSlice<'a, T> {
+    /// View the stored data as a slice.
+    fn as_ref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.base, self.len) }
+    }
+}
+
+/// Iterates over the elements of a [`CSlice`].
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CSlice;
+///
+/// let slice = &[0, 1, 2];
+/// let ptr = slice.as_ptr();
+/// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+/// for elem in &cslice {
+///     println!("=> {}", elem);
+/// }
+/// ```
+impl<'a, 'b, T> IntoIterator for &'b CSlice<'a, T> {
+    type Item = &'b T;
+    type IntoIter = CSliceIter<'a, 'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> Index<usize> for CSlice<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        assert!(index < self.len);
+        unsafe { &*self.base.add(index) }
+    }
+}
+
+impl<'a, T: Clone> From<CSlice<'a, T>> for Vec<T> {
+    fn from(slice: CSlice<'a, T>) -> Vec<T> {
+        let mut v = Vec::with_capacity(slice.len);
+        v.extend_from_slice(slice.as_ref());
+        v
+    }
+}
+
+/// Iterator over [`CSliceMut`].
+///
+/// You can get it from the [`CSliceMut::iter`] method.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CSliceMut;
+///
+/// let slice = &mut [0, 1, 2];
+/// let ptr = slice.as_mut_ptr();
+/// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+/// let iter = cslice.iter();
+/// ```
+pub struct CSliceMutIter<'a, 'b, T> {
+    inner: &'b CSliceMut<'a, T>,
+    pos: usize,
+}
+
+impl<'a, 'b, T> Iterator for CSliceMutIter<'a, 'b, T> {
+    type Item = &'b T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.inner.len() {
+            None
+        } else {
+            self.pos += 1;
+            Some(unsafe { self.inner.get_unchecked(self.pos - 1) })
+        }
+    }
+}
+
+/// Mutable iterator over [`CSliceMut`].
+///
+/// You can get it from the [`CSliceMut::iter_mut`] method.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CSliceMut;
+///
+/// let slice = &mut [0, 1, 2];
+/// let ptr = slice.as_mut_ptr();
+/// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+/// let iter = cslice.iter_mut();
+/// ```
+pub struct CSliceMutIterMut<'a, 'b, T> {
+    inner: &'b mut CSliceMut<'a, T>,
+    pos: usize,
+}
+
+impl<'a, 'b, T> Iterator for CSliceMutIterMut<'a, 'b, T> {
+    type Item = &'b mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.inner.len() {
+            None
+        } else {
+            self.pos += 1;
+            Some(unsafe { &mut *self.inner.base.add(self.pos - 1) })
+        }
+    }
+}
+
+/// The type representing an 'unsafe' mutable foreign chunk of memory.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CSliceMut;
+///
+/// let slice = &mut [0, 1, 2];
+/// let ptr = slice.as_mut_ptr();
+/// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+/// ```
+pub struct CSliceMut<'a, T> {
+    pub(crate) base: *mut T,
+    pub(crate) len: usize,
+    pub(crate) _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a, T> CSliceMut<'a, T> {
+    /// Create a `CSlice` from a raw pointer to a buffer with a given length.
+    ///
+    /// Panics if the given pointer is null. The returned slice will not attempt
+    /// to deallocate the slice when dropped.
+    ///
+    /// # Safety
+    ///
+    /// `base` must point to `len` initialized elements which remain valid for
+    /// the whole lifetime of the returned slice.
+    ///
+    /// # Arguments
+    ///
+    /// * base - A raw pointer to a buffer
+    /// * len - The number of elements in the buffer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// ```
+    pub unsafe fn new(base: *mut T, len: usize) -> CSliceMut<'a, T> {
+        assert!(!base.is_null());
+        Self {
+            base,
+            len,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Create a `CSliceMut` from a non-null pointer to a buffer with a given length.
+    ///
+    /// # Safety
+    ///
+    /// `base` must point to `len` initialized elements which remain valid for
+    /// the whole lifetime of the returned slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    /// use std::ptr::NonNull;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = NonNull::new(slice.as_mut_ptr()).unwrap();
+    /// let cslice = unsafe { CSliceMut::from_non_null(ptr, slice.len()) };
+    /// ```
+    pub unsafe fn from_non_null(base: NonNull<T>, len: usize) -> CSliceMut<'a, T> {
+        CSliceMut {
+            base: base.as_ptr(),
+            len,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Retrieves an element at a given index, returning `None` if the requested
+    /// index is greater than the length of the slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.get(1), slice.get(1));
+    /// ```
+    pub fn get(&self, ofs: usize) -> Option<&T> {
+        if ofs < self.len {
+            Some(unsafe { &*self.base.add(ofs) })
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves the element at `ofs` modulo the length of the slice,
+    /// returning [`None`] only if the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.get_wrapping(4), Some(&1));
+    /// ```
+    pub fn get_wrapping(&self, ofs: usize) -> Option<&T> {
+        ofs.checked_rem(self.len)
+            .map(|ofs| unsafe { &*self.base.add(ofs) })
+    }
+
+    /// Returns a reference to an element without doing any check.
+    ///
+    /// # Safety
+    ///
+    /// `ofs` must be lower than the length of the slice. With the
+    /// `debug-bounds` feature, debug builds panic if it isn't.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// unsafe {
+    ///     assert_eq!(cslice.get_unchecked(1), slice.get_unchecked(1));
+    /// }
+    /// ```
+    pub unsafe fn get_unchecked(&self, ofs: usize) -> &T {
+        #[cfg(feature = "debug-bounds")]
+        debug_assert!(ofs < self.len);
+        &*self.base.add(ofs)
+    }
+
+    /// Retrieves a mutable element at a given index, returning `None` if the
+    /// requested index is greater than the length of the slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// if let Some(el) = cslice.get_mut(1) {
+    ///     *el += 10;
+    /// }
+    /// assert_eq!(cslice[1], 11);
+    /// ```
+    pub fn get_mut(&mut self, ofs: usize) -> Option<&mut T> {
+        if ofs < self.len {
+            Some(unsafe { &mut *self.base.add(ofs) })
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves a mutable element at `ofs` modulo the length of the slice,
+    /// returning [`None`] only if the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// if let Some(el) = cslice.get_wrapping_mut(4) {
+    ///     *el += 10;
+    /// }
+    /// assert_eq!(cslice[1], 11);
+    /// ```
+    pub fn get_wrapping_mut(&mut self, ofs: usize) -> Option<&mut T> {
+        ofs.checked_rem(self.len)
+            .map(|ofs| unsafe { &mut *self.base.add(ofs) })
+    }
+
+    /// Returns a mutable reference to an element without doing any check.
+    ///
+    /// # Safety
+    ///
+    /// `ofs` must be lower than the length of the slice. With the
+    /// `debug-bounds` feature, debug builds panic if it isn't.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// unsafe { *cslice.get_unchecked_mut(1) += 10; }
+    /// assert_eq!(cslice[1], 11);
+    /// ```
+    pub unsafe fn get_unchecked_mut(&mut self, ofs: usize) -> &mut T {
+        #[cfg(feature = "debug-bounds")]
+        debug_assert!(ofs < self.len);
+        &mut *self.base.add(ofs)
+    }
+
+    /// Returns mutable references to the elements at the given indices at once,
+    /// returning [`None`] if any index is out of bounds or if two indices are
+    /// equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// if let Some([a, b]) = cslice.get_many_mut([0, 2]) {
+    ///     std::mem::swap(a, b);
+    /// }
+    /// assert_eq!(cslice[0], 2);
+    /// assert!(cslice.get_many_mut([1, 1]).is_none());
+    /// ```
+    pub fn get_many_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        for (pos, &idx) in indices.iter().enumerate() {
+            if idx >= self.len || indices[..pos].contains(&idx) {
+                return None;
+            }
+        }
+        Some(indices.map(|idx| unsafe { &mut *self.base.add(idx) }))
+    }
+
+    /// Returns the number of items in this slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.len(), slice.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether this slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.is_empty(), slice.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a description of the memory used by the slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0u32, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// let layout = cslice.layout();
+    /// assert_eq!(layout.byte_len, 12);
+    /// ```
+    pub fn layout(&self) -> BufferLayout {
+        BufferLayout::new(self.base, self.len)
+    }
+
+    /// Returns `true` if the whole slice lies inside the memory region of
+    /// `region_len` bytes starting at `region_start`, which is useful to check
+    /// that a foreign buffer really points into an expected arena.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let region = &mut [0u32; 8];
+    /// let cslice = unsafe { CSliceMut::new(region.as_mut_ptr().add(2), 3) };
+    /// assert!(cslice.is_within(region.as_ptr() as *const u8, 32));
+    /// assert!(!cslice.is_within(region.as_ptr() as *const u8, 16));
+    /// ```
+    pub fn is_within(&self, region_start: *const u8, region_len: usize) -> bool {
+        self.layout().is_within(region_start, region_len)
+    }
+
+    /// Splits the slice into two mutable halves of the same length, or returns
+    /// [`None`] if its length is odd.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// let (mut left, right) = cslice.split_even_mut().unwrap();
+    /// left.copy_from_slice(right.as_ref());
+    /// assert_eq!(cslice.as_ref(), [2, 3, 2, 3]);
+    /// ```
+    pub fn split_even_mut(&mut self) -> Option<(CSliceMut<'_, T>, CSliceMut<'_, T>)> {
+        if !self.len.is_multiple_of(2) {
+            return None;
+        }
+        let half = self.len / 2;
+        Some((
+            CSliceMut {
+                base: self.base,
+                len: half,
+                _phantom: PhantomData,
+            },
+            CSliceMut {
+                base: unsafe { self.base.add(half) },
+                len: half,
+                _phantom: PhantomData,
+            },
+        ))
+    }
+
+    /// Splits the slice into `n` interleaved channels: the channel `c` goes
+    /// over the elements at `c`, `c + n`, `c + 2 * n`, etc.
+    ///
+    /// Panics if `n` is 0 or if the length of the slice isn't a multiple of
+    /// `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [1, 10, 2, 20];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// for (gain, channel) in cslice.channels_mut(2).into_iter().enumerate() {
+    ///     for sample in channel {
+    ///         *sample *= gain + 1;
+    ///     }
+    /// }
+    /// assert_eq!(cslice.as_ref(), [1, 20, 2, 40]);
+    /// ```
+    pub fn channels_mut(&mut self, n: usize) -> Vec<ChannelView<'_, T>> {
+        channel::channels(self.base, self.len, n)
+    }
+
+    /// Removes the first element from the slice and returns it, or returns
+    /// [`None`] if the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// *cslice.take_first().unwrap() = 3;
+    /// assert_eq!(cslice.as_ref(), [1, 2]);
+    /// ```
+    pub fn take_first(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        let first = self.base;
+        self.base = unsafe { self.base.add(1) };
+        self.len -= 1;
+        Some(unsafe { &mut *first })
+    }
+
+    /// Removes the last element from the slice and returns it, or returns
+    /// [`None`] if the slice is empty.
     ///
     /// # Example
     ///
@@ -346,57 +2216,74 @@ impl<'a, T> CSliceMut<'a, T> {
     ///
     /// let slice = &mut [0, 1, 2];
     /// let ptr = slice.as_mut_ptr();
-    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
-    /// unsafe {
-    ///     assert_eq!(cslice.get_unchecked(1), slice.get_unchecked(1));
-    /// }
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// *cslice.take_last().unwrap() = 3;
+    /// assert_eq!(cslice.as_ref(), [0, 1]);
     /// ```
-    pub unsafe fn get_unchecked(&self, ofs: usize) -> &T {
-        &*self.base.add(ofs)
+    pub fn take_last(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(unsafe { &mut *self.base.add(self.len) })
     }
 
-    /// Retrieves a mutable element at a given index, returning `None` if the
-    /// requested index is greater than the length of the slice.
+    /// Fills the slice by repeating `pattern`. The last repetition is cut
+    /// if the length isn't a multiple of the pattern length.
+    ///
+    /// Panics if `pattern` is empty.
     ///
     /// # Example
     ///
     /// ```
     /// use c_vec::CSliceMut;
     ///
-    /// let slice = &mut [0, 1, 2];
+    /// let slice = &mut [0; 5];
     /// let ptr = slice.as_mut_ptr();
     /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
-    /// if let Some(el) = cslice.get_mut(1) {
-    ///     *el += 10;
-    /// }
-    /// assert_eq!(cslice[1], 11);
+    /// cslice.fill_pattern(&[1, 2]);
+    /// assert_eq!(cslice.as_ref(), [1, 2, 1, 2, 1]);
     /// ```
-    pub fn get_mut(&mut self, ofs: usize) -> Option<&mut T> {
-        if ofs < self.len {
-            Some(unsafe { &mut *self.base.add(ofs) })
-        } else {
-            None
+    pub fn fill_pattern(&mut self, pattern: &[T])
+    where
+        T: Copy,
+    {
+        assert!(!pattern.is_empty());
+        for (dst, src) in self.as_mut().iter_mut().zip(pattern.iter().cycle()) {
+            *dst = *src;
         }
     }
 
-    /// Returns a mutable reference to an element without doing any check.
+    /// Writes clones of `value` into the elements from `valid` to the end of
+    /// the slice, typically after a foreign call initialized only the
+    /// first `valid` ones. The overwritten elements are considered as
+    /// uninitialized so they aren't dropped.
+    ///
+    /// Panics if `valid` is greater than the length of the slice.
     ///
     /// # Example
     ///
     /// ```
     /// use c_vec::CSliceMut;
     ///
-    /// let slice = &mut [0, 1, 2];
+    /// let slice = &mut [1, 2, 3, 4];
     /// let ptr = slice.as_mut_ptr();
     /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
-    /// unsafe { *cslice.get_unchecked_mut(1) += 10; }
-    /// assert_eq!(cslice[1], 11);
+    /// cslice.init_tail(1, 0);
+    /// assert_eq!(cslice.as_ref(), [1, 0, 0, 0]);
     /// ```
-    pub unsafe fn get_unchecked_mut(&mut self, ofs: usize) -> &mut T {
-        &mut *self.base.add(ofs)
+    pub fn init_tail(&mut self, valid: usize, value: T)
+    where
+        T: Clone,
+    {
+        assert!(valid <= self.len);
+        for ofs in valid..self.len {
+            unsafe { ptr::write(self.base.add(ofs), value.clone()) };
+        }
     }
 
-    /// Returns the number of items in this slice.
+    /// Calls `f` on each element of the slice, stopping at the first error,
+    /// which is returned.
     ///
     /// # Example
     ///
@@ -405,27 +2292,375 @@ impl<'a, T> CSliceMut<'a, T> {
     ///
     /// let slice = &mut [0, 1, 2];
     /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// let res = cslice.try_for_each_mut(|x| {
+    ///     *x += 1;
+    ///     if *x < 2 { Ok(()) } else { Err(*x) }
+    /// });
+    /// assert_eq!(res, Err(2));
+    /// assert_eq!(cslice.as_ref(), [1, 2, 2]);
+    /// ```
+    pub fn try_for_each_mut<E, F>(&mut self, f: F) -> Result<(), E>
+    where
+        F: FnMut(&mut T) -> Result<(), E>,
+    {
+        self.as_mut().iter_mut().try_for_each(f)
+    }
+
+    /// Calls `f` on each element which has both a left and a right neighbor,
+    /// passing copies of the original value of these neighbors (not the ones
+    /// already updated by `f`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [1, 2, 3, 4];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// cslice.update_with_neighbors(|x, l, r| *x += l + r);
+    /// assert_eq!(cslice.as_ref(), [1, 6, 9, 4]);
+    /// ```
+    pub fn update_with_neighbors<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T, &T, &T),
+        T: Copy,
+    {
+        let data = self.as_mut();
+        if data.len() < 3 {
+            return;
+        }
+        let mut left = data[0];
+        for pos in 1..data.len() - 1 {
+            let (current, right) = (data[pos], data[pos + 1]);
+            f(&mut data[pos], &left, &right);
+            left = current;
+        }
+    }
+
+    /// Returns the number of elements of the slice matching `pred`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
     /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
-    /// assert_eq!(cslice.len(), slice.len());
+    /// assert_eq!(cslice.count(|x| x % 2 == 1), 2);
     /// ```
-    pub fn len(&self) -> usize {
-        self.len
+    pub fn count<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_ref().iter().filter(|x| pred(x)).count()
     }
 
-    /// Returns whether this slice is empty.
+    /// Returns whether all the elements of the slice match `pred`.
     ///
     /// # Example
     ///
     /// ```
     /// use c_vec::CSliceMut;
     ///
-    /// let slice = &mut [0, 1, 2];
+    /// let slice = &mut [1, 2, 3];
     /// let ptr = slice.as_mut_ptr();
     /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
-    /// assert_eq!(cslice.is_empty(), slice.is_empty());
+    /// assert!(cslice.all(|x| *x > 0));
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn all<P>(&self, pred: P) -> bool
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_ref().iter().all(pred)
+    }
+
+    /// Returns whether any element of the slice matches `pred`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// assert!(cslice.any(|x| *x == 2));
+    /// ```
+    pub fn any<P>(&self, pred: P) -> bool
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_ref().iter().any(pred)
+    }
+
+    /// Returns a view over the slice as rows of `W` elements, or [`None`] if
+    /// the length isn't a multiple of `W`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2, 3, 4, 5];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// let rows = cslice.as_rows::<3>().unwrap();
+    /// assert_eq!(rows[1], [3, 4, 5]);
+    /// assert!(cslice.as_rows::<4>().is_none());
+    /// ```
+    pub fn as_rows<const W: usize>(&self) -> Option<&[[T; W]]> {
+        if W == 0 || !self.len.is_multiple_of(W) {
+            return None;
+        }
+        Some(unsafe { slice::from_raw_parts(self.base as *const [T; W], self.len / W) })
+    }
+
+    /// Returns a mutable view over the slice as rows of `W` elements, or
+    /// [`None`] if the length isn't a multiple of `W`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2, 3, 4, 5];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// cslice.as_rows_mut::<2>().unwrap()[2] = [0, 0];
+    /// assert_eq!(cslice[4], 0);
+    /// ```
+    pub fn as_rows_mut<const W: usize>(&mut self) -> Option<&mut [[T; W]]> {
+        if W == 0 || !self.len.is_multiple_of(W) {
+            return None;
+        }
+        Some(unsafe { slice::from_raw_parts_mut(self.base as *mut [T; W], self.len / W) })
+    }
+
+    /// Returns a [`CBufBuilder`] filling this slice from its beginning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0; 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// let mut builder = cslice.builder();
+    /// builder.push(1).unwrap();
+    /// ```
+    pub fn builder(self) -> CBufBuilder<'a, T> {
+        CBufBuilder::new(self)
+    }
+
+    /// Splits the slice into `n` non-overlapping mutable slices of roughly the
+    /// same length. When the length isn't a multiple of `n`, the first slices
+    /// get one more element than the last ones.
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2, 3, 4];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// let parts = cslice.split_into_parts(2);
+    /// assert_eq!(parts[0].as_ref(), [0, 1, 2]);
+    /// assert_eq!(parts[1].as_ref(), [3, 4]);
+    /// ```
+    pub fn split_into_parts(self, n: usize) -> Vec<CSliceMut<'a, T>> {
+        assert!(n != 0);
+        let (size, rem) = (self.len / n, self.len % n);
+        let mut parts = Vec::with_capacity(n);
+        let mut pos = 0;
+        for i in 0..n {
+            let len = if i < rem { size + 1 } else { size };
+            parts.push(CSliceMut {
+                base: unsafe { self.base.add(pos) },
+                len,
+                _phantom: PhantomData,
+            });
+            pos += len;
+        }
+        parts
+    }
+
+    /// Rotates the slice in-place so that the element at `mid` becomes the
+    /// first one. No allocation is performed.
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// cslice.rotate_left_inplace(1);
+    /// assert_eq!(cslice.as_ref(), [1, 2, 3, 0]);
+    /// ```
+    pub fn rotate_left_inplace(&mut self, mid: usize) {
+        assert!(mid <= self.len);
+        self.reverse_range(0, mid);
+        self.reverse_range(mid, self.len);
+        self.reverse_range(0, self.len);
+    }
+
+    /// Rotates the slice in-place so that the last `k` elements become the
+    /// first ones. No allocation is performed.
+    ///
+    /// Panics if `k > len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// cslice.rotate_right_inplace(1);
+    /// assert_eq!(cslice.as_ref(), [3, 0, 1, 2]);
+    /// ```
+    pub fn rotate_right_inplace(&mut self, k: usize) {
+        assert!(k <= self.len);
+        self.rotate_left_inplace(self.len - k);
+    }
+
+    fn reverse_range(&mut self, mut start: usize, mut end: usize) {
+        while start + 1 < end {
+            end -= 1;
+            unsafe { ptr::swap(self.base.add(start), self.base.add(end)) };
+            start += 1;
+        }
+    }
+
+    /// Copies all the elements from `src` into the slice.
+    ///
+    /// Panics if `src` doesn't have the same length as the slice. `src` is
+    /// expected not to overlap with the slice: debug builds panic if it does
+    /// while release builds fall back to an overlapping copy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0; 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// cslice.copy_from_slice(&[1, 2, 3]);
+    /// assert_eq!(cslice.as_ref(), [1, 2, 3]);
+    /// ```
+    pub fn copy_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        assert_eq!(self.len, src.len());
+        let dst = self.base as *const T..self.base.wrapping_add(self.len) as *const T;
+        let src = src.as_ptr_range();
+        let overlap = src.start < dst.end && dst.start < src.end;
+        debug_assert!(!overlap, "source and destination overlap");
+        unsafe {
+            if overlap {
+                ptr::copy(src.start, self.base, self.len);
+            } else {
+                ptr::copy_nonoverlapping(src.start, self.base, self.len);
+            }
+        }
+    }
+
+    /// Overwrites the elements starting at `ofs` with `data`. The original
+    /// elements are restored when the returned guard is dropped.
+    ///
+    /// Panics if `data` doesn't fit in the slice starting at `ofs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// drop(cslice.scoped_overwrite(0, &[3, 4]));
+    /// assert_eq!(cslice.as_ref(), [0, 1, 2]);
+    /// ```
+    pub fn scoped_overwrite(&mut self, ofs: usize, data: &[T]) -> RestoreGuard<'_, T>
+    where
+        T: Copy,
+    {
+        RestoreGuard::new(
+            CSliceMut {
+                base: self.base,
+                len: self.len,
+                _phantom: PhantomData,
+            },
+            ofs,
+            data,
+        )
+    }
+
+    /// Reorders the slice so that the element at `index` is at its final
+    /// sorted position, with smaller or equal elements before it and greater
+    /// or equal elements after it. See [`slice::select_nth_unstable`].
+    ///
+    /// Returns the elements before `index`, the element at `index` and the
+    /// elements after it.
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [4, 0, 2, 3, 1];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// let (_, median, _) = cslice.select_nth_unstable(2);
+    /// assert_eq!(*median, 2);
+    /// ```
+    pub fn select_nth_unstable(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T])
+    where
+        T: Ord,
+    {
+        self.as_mut().select_nth_unstable(index)
+    }
+
+    /// Same as [`CSliceMut::select_nth_unstable`] but uses `compare` to order the
+    /// elements.
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [4, 0, 2, 3, 1];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// let (_, elem, _) = cslice.select_nth_unstable_by(1, |a, b| b.cmp(a));
+    /// assert_eq!(*elem, 3);
+    /// ```
+    pub fn select_nth_unstable_by<F>(
+        &mut self,
+        index: usize,
+        compare: F,
+    ) -> (&mut [T], &mut T, &mut [T])
+    where
+        F: FnMut(&T, &T) -> cmp::Ordering,
+    {
+        self.as_mut().select_nth_unstable_by(index, compare)
     }
 
     /// Returns an iterator over `CSliceMut`.
@@ -470,6 +2705,112 @@ impl<'a, T> CSliceMut<'a, T> {
             pos: 0,
         }
     }
+
+    /// Returns a [`slice::Iter`] over the `CSliceMut` data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// assert_eq!(cslice.std_iter().rev().collect::<Vec<_>>(), [&2, &1, &0]);
+    /// ```
+    pub fn std_iter(&self) -> slice::Iter<'_, T> {
+        self.as_ref().iter()
+    }
+
+    /// Returns a [`slice::IterMut`] over the `CSliceMut` data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// cslice.std_iter_mut().for_each(|x| *x += 1);
+    /// assert_eq!(cslice.as_ref(), [1, 2, 3]);
+    /// ```
+    pub fn std_iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.as_mut().iter_mut()
+    }
+}
+
+macro_rules! write_int {
+    ($($name:ident => $ty:ident::$to:ident, $endian:literal, $read:ident;)*) => {
+        $(
+            #[doc = concat!(
+                "Writes a `", stringify!($ty), "` in ", $endian,
+                " endian at the given byte offset, returning `false` (and writing nothing) if",
+                " the slice is too short.",
+            )]
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// use c_vec::{CSlice, CSliceMut};
+            ///
+            /// let slice = &mut [0u8; 9];
+            /// let ptr = slice.as_mut_ptr();
+            /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+            #[doc = concat!("assert!(cslice.", stringify!($name), "(1, 7));")]
+            #[doc = concat!("assert!(!cslice.", stringify!($name), "(9, 7));")]
+            /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+            #[doc = concat!("assert_eq!(cslice.", stringify!($read), "(1), Some(7));")]
+            /// ```
+            pub fn $name(&mut self, ofs: usize, val: $ty) -> bool {
+                let bytes = val.$to();
+                match ofs
+                    .checked_add(bytes.len())
+                    .and_then(|end| self.as_mut().get_mut(ofs..end))
+                {
+                    Some(dst) => {
+                        dst.copy_from_slice(&bytes);
+                        true
+                    }
+                    None => false,
+                }
+            }
+        )*
+    };
+}
+
+impl<'a> CSliceMut<'a, u8> {
+    write_int! {
+        write_u16_le => u16::to_le_bytes, "little", read_u16_le;
+        write_u16_be => u16::to_be_bytes, "big", read_u16_be;
+        write_u32_le => u32::to_le_bytes, "little", read_u32_le;
+        write_u32_be => u32::to_be_bytes, "big", read_u32_be;
+        write_u64_le => u64::to_le_bytes, "little", read_u64_le;
+        write_u64_be => u64::to_be_bytes, "big", read_u64_be;
+    }
+
+    /// Returns a [`CWriteCursor`] writing bytes into this slice, starting at
+    /// its beginning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    /// use std::io::Write;
+    ///
+    /// let slice = &mut [0u8; 8];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// write!(cslice.writer(), "{}", 42).unwrap();
+    /// assert_eq!(&cslice.as_ref()[..2], b"42");
+    /// ```
+    pub fn writer(&mut self) -> CWriteCursor<'_> {
+        CWriteCursor::new(CSliceMut {
+            base: self.base,
+            len: self.len,
+            _phantom: PhantomData,
+        })
+    }
 }
 
 impl<'a, T> AsRef<[T]> for CSliceMut<'a, T> {
@@ -486,6 +2827,29 @@ impl<'a, T> AsMut<[T]> for CSliceMut<'a, T> {
     }
 }
 
+/// Iterates over the elements of a [`CSliceMut`].
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CSliceMut;
+///
+/// let slice = &mut [0, 1, 2];
+/// let ptr = slice.as_mut_ptr();
+/// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+/// for elem in &cslice {
+///     println!("=> {}", elem);
+/// }
+/// ```
+impl<'a, 'b, T> IntoIterator for &'b CSliceMut<'a, T> {
+    type Item = &'b T;
+    type IntoIter = CSliceMutIter<'a, 'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl<'a, T> Index<usize> for CSliceMut<'a, T> {
     type Output = T;
 
@@ -502,10 +2866,10 @@ impl<'a, T> IndexMut<usize> for CSliceMut<'a, T> {
     }
 }
 
-impl<'a, T: Clone> Into<Vec<T>> for CSliceMut<'a, T> {
-    fn into(self: CSliceMut<'a, T>) -> Vec<T> {
-        let mut v = Vec::with_capacity(self.len);
-        v.extend_from_slice(self.as_ref());
+impl<'a, T: Clone> From<CSliceMut<'a, T>> for Vec<T> {
+    fn from(slice: CSliceMut<'a, T>) -> Vec<T> {
+        let mut v = Vec::with_capacity(slice.len);
+        v.extend_from_slice(slice.as_ref());
         v
     }
 }
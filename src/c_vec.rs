@@ -7,11 +7,25 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::array;
+use std::cmp::{self, Ordering};
+use std::ffi::{CString, NulError};
+use std::iter::{Copied, Rev, Sum};
 use std::marker::PhantomData;
-use std::ops::{Index, IndexMut};
+use std::mem::{self, MaybeUninit};
+use std::ops::{Index, IndexMut, RangeBounds};
+#[cfg(feature = "catch-dtor-panic")]
+use std::panic;
+use std::pin::Pin;
+use std::ptr::{self, NonNull};
 use std::slice;
 
-use crate::{CSlice, CSliceMut};
+use crate::channel::{self, ChannelView};
+use crate::checksum::Checksum;
+use crate::{
+    BufferLayout, CReadCursor, CSlice, CSliceMut, CSliceSplit, CWriteCursor, FrozenCVec,
+    RestoreGuard, SharedCVec,
+};
 
 /// Iterator over [`CVec`].
 ///
@@ -30,21 +44,45 @@ use crate::{CSlice, CSliceMut};
 pub struct CVecIter<'a, T: 'a> {
     inner: &'a CVec<T>,
     pos: usize,
+    end: usize,
+    len: usize,
 }
 
 impl<'a, T> Iterator for CVecIter<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.pos >= self.inner.len() {
+        // The length can only change behind our back if some foreign code
+        // mutated the vector while it was borrowed.
+        debug_assert_eq!(self.len, self.inner.len());
+        if self.pos >= self.end {
             None
         } else {
             self.pos += 1;
             Some(unsafe { self.inner.get_unchecked(self.pos - 1) })
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.pos;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for CVecIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        debug_assert_eq!(self.len, self.inner.len());
+        if self.pos >= self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(unsafe { self.inner.get_unchecked(self.end) })
+        }
+    }
 }
 
+impl<'a, T> ExactSizeIterator for CVecIter<'a, T> {}
+
 /// Mutable iterator over [`CVec`].
 ///
 /// You can get it from the [`CVec::iter_mut`] method.
@@ -72,7 +110,80 @@ impl<'a, T> Iterator for CVecIterMut<'a, T> {
             None
         } else {
             self.pos += 1;
-            Some(unsafe { &mut *self.inner.base.add(self.pos - 1) })
+            Some(unsafe { &mut *self.inner.ptr_at(self.pos - 1) })
+        }
+    }
+}
+
+/// Owning iterator over [`CVec`] yielding the elements from the last one to
+/// the first one.
+///
+/// You can get it from the [`CVec::into_iter_rev`] method.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CVec;
+///
+/// let cvec = CVec::from(vec![0, 1, 2]);
+/// let iter = cvec.into_iter_rev();
+/// ```
+pub struct CVecIntoIterRev<T> {
+    inner: CVec<T>,
+}
+
+impl<T: Clone> Iterator for CVecIntoIterRev<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let last = self.inner.len.checked_sub(1)?;
+        unsafe {
+            let elem = self.inner.ptr_at(last);
+            let elem = if self.inner.owns_elements {
+                ptr::read(elem)
+            } else {
+                (*elem).clone()
+            };
+            // The element was moved out so it must not be dropped again.
+            self.inner.set_len(last);
+            Some(elem)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.inner.len, Some(self.inner.len))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for CVecIntoIterRev<T> {}
+
+// The destructor of a `CVec`: a bare function pointer doesn't need to be boxed.
+enum Dtor<T> {
+    Boxed(Box<dyn FnOnce(*mut T)>),
+    Fn(fn(*mut T)),
+    // The buffer comes from a `Vec` with the given capacity.
+    Vec(usize),
+}
+
+impl<T> Dtor<T> {
+    fn call(self, base: *mut T) {
+        match self {
+            Dtor::Boxed(f) => f(base),
+            Dtor::Fn(f) => f(base),
+            Dtor::Vec(cap) => unsafe { drop(Vec::from_raw_parts(base, 0, cap)) },
+        }
+    }
+
+    fn into_boxed(self) -> Box<dyn FnOnce(*mut T)>
+    where
+        T: 'static,
+    {
+        match self {
+            Dtor::Boxed(f) => f,
+            Dtor::Fn(f) => Box::new(f),
+            Dtor::Vec(cap) => {
+                Box::new(move |base| unsafe { drop(Vec::from_raw_parts(base, 0, cap)) })
+            }
         }
     }
 }
@@ -89,15 +200,35 @@ impl<'a, T> Iterator for CVecIterMut<'a, T> {
 /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
 /// ```
 pub struct CVec<T> {
+    // The pointer given to the destructor.
     base: *mut T,
+    // Offset from `base` of the first element in memory, which can only be
+    // non-zero for a reversed vector which was shortened.
+    start: usize,
     len: usize,
-    dtor: Option<Box<dyn FnOnce(*mut T)>>,
+    dtor: Option<Dtor<T>>,
+    // Whether the elements are stored from the last one to the first one.
+    reversed: bool,
+    // Whether the elements must be dropped before calling the destructor.
+    owns_elements: bool,
+    #[cfg(feature = "debug-bounds")]
+    alloc_len: usize,
 }
 
 impl<T> Drop for CVec<T> {
     fn drop(&mut self) {
+        if self.owns_elements {
+            unsafe { self.drop_elements() };
+        }
         if let Some(f) = self.dtor.take() {
-            f(self.base);
+            #[cfg(not(feature = "catch-dtor-panic"))]
+            f.call(self.base);
+            #[cfg(feature = "catch-dtor-panic")]
+            {
+                let base = self.base;
+                // The panic message is still printed by the panic hook.
+                let _ = panic::catch_unwind(panic::AssertUnwindSafe(move || f.call(base)));
+            }
         }
     }
 }
@@ -108,6 +239,11 @@ impl<T> CVec<T> {
     /// Panics if the given pointer is null. The returned vector will not attempt
     /// to deallocate the vector when dropped.
     ///
+    /// # Safety
+    ///
+    /// `base` must point to `len` initialized elements which remain valid for
+    /// the whole lifetime of the returned vector.
+    ///
     /// # Arguments
     ///
     /// * base - A unique pointer to a buffer
@@ -128,21 +264,169 @@ impl<T> CVec<T> {
             base,
             len,
             dtor: None,
+            reversed: false,
+            start: 0,
+            owns_elements: false,
+            #[cfg(feature = "debug-bounds")]
+            alloc_len: len,
+        }
+    }
+
+    /// Create a `CVec` from a non-null pointer to a buffer with a given length.
+    ///
+    /// The returned vector will not attempt to deallocate the vector when
+    /// dropped.
+    ///
+    /// # Safety
+    ///
+    /// `base` must point to `len` initialized elements which remain valid for
+    /// the whole lifetime of the returned vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    /// use std::ptr::NonNull;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = NonNull::new(slice.as_mut_ptr()).unwrap();
+    /// let cvec = unsafe { CVec::from_non_null(ptr, slice.len()) };
+    /// ```
+    pub unsafe fn from_non_null(base: NonNull<T>, len: usize) -> CVec<T> {
+        CVec {
+            base: base.as_ptr(),
+            len,
+            dtor: None,
+            reversed: false,
+            start: 0,
+            owns_elements: false,
+            #[cfg(feature = "debug-bounds")]
+            alloc_len: len,
+        }
+    }
+
+    /// Create a `CVec` from a non-null pointer to a buffer with a given length,
+    /// and a function to run upon destruction.
+    ///
+    /// # Safety
+    ///
+    /// `base` must point to `len` initialized elements which remain valid until
+    /// `dtor` is called.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    /// use std::ptr::NonNull;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = NonNull::new(slice.as_mut_ptr()).unwrap();
+    /// let cvec = unsafe {
+    ///     CVec::from_non_null_with_dtor(ptr, slice.len(), |_| println!("free time!"))
+    /// };
+    /// ```
+    pub unsafe fn from_non_null_with_dtor<F>(base: NonNull<T>, len: usize, dtor: F) -> CVec<T>
+    where
+        F: FnOnce(*mut T) + 'static,
+    {
+        CVec {
+            base: base.as_ptr(),
+            len,
+            dtor: Some(Dtor::Boxed(Box::new(dtor))),
+            reversed: false,
+            start: 0,
+            owns_elements: false,
+            #[cfg(feature = "debug-bounds")]
+            alloc_len: len,
         }
     }
 
+    /// Create a `CVec` from a pointer to the last element of a buffer laid out
+    /// in reverse order: the element at index 0 is `*end`, the element at index
+    /// 1 is `*end.sub(1)`, etc.
+    ///
+    /// Indexing, [`CVec::get`], [`CVec::get_mut`], their unchecked variants,
+    /// [`CVec::get_many_mut`], [`CVec::iter`] and [`CVec::iter_mut`] follow this
+    /// logical order, as well as the methods computing values from the
+    /// elements. [`AsRef`], [`AsMut`], [`CVec::as_memory_cslice`] and
+    /// [`CVec::as_memory_cslice_mut`] give access to the elements in memory
+    /// order. The other methods returning views over the buffer, like
+    /// [`CVec::as_cslice`] or [`CVec::prefix`], can't expose the elements in
+    /// reverse order and panic on a reversed vector.
+    ///
+    /// Panics if the given pointer is null. The returned vector will not attempt
+    /// to deallocate the vector when dropped.
+    ///
+    /// # Safety
+    ///
+    /// `end` and the `len - 1` elements before it must be initialized and
+    /// remain valid for the whole lifetime of the returned vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let end = unsafe { slice.as_mut_ptr().add(2) };
+    /// let cvec = unsafe { CVec::new_reversed(end, slice.len()) };
+    /// assert_eq!(cvec[0], 2);
+    /// assert_eq!(cvec.get(2), Some(&0));
+    /// ```
+    pub unsafe fn new_reversed(end: *mut T, len: usize) -> CVec<T> {
+        assert!(!end.is_null());
+        let mut cvec = CVec::new(end.add(1).sub(len), len);
+        cvec.reversed = true;
+        cvec
+    }
+
+    /// Create a `CVec` from a raw pointer to a buffer with a given length,
+    /// checking that `len` elements fit in an allocation of `alloc_bytes`
+    /// bytes.
+    ///
+    /// Panics if the given pointer is null or if `len` elements don't fit in
+    /// `alloc_bytes`. The returned vector will not attempt to deallocate the
+    /// vector when dropped.
+    ///
+    /// # Safety
+    ///
+    /// `base` must point to an allocation of `alloc_bytes` bytes containing
+    /// `len` initialized elements which remain valid for the whole lifetime of
+    /// the returned vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0u32, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new_checked(ptr, slice.len(), 12) };
+    /// ```
+    pub unsafe fn new_checked(base: *mut T, len: usize, alloc_bytes: usize) -> CVec<T> {
+        assert!(len
+            .checked_mul(mem::size_of::<T>())
+            .is_some_and(|bytes| bytes <= alloc_bytes));
+        CVec::new(base, len)
+    }
+
     /// Create a `CVec` from a foreign buffer, with a given length,
     /// and a function to run upon destruction.
     ///
     /// Panics if the given pointer is null.
     ///
+    /// # Safety
+    ///
+    /// `base` must point to `len` initialized elements which remain valid until
+    /// `dtor` is called.
+    ///
     /// # Arguments
     ///
     /// * base - A unique pointer to a buffer
     /// * len - The number of elements in the buffer
     /// * dtor - A fn to run when the value is destructed, useful
-    ///          for freeing the buffer, etc. `base` will be passed
-    ///          to it as an argument.
+    ///   for freeing the buffer, etc. `base` will be passed
+    ///   to it as an argument.
     ///
     /// # Example
     ///
@@ -158,78 +442,120 @@ impl<T> CVec<T> {
         F: FnOnce(*mut T) + 'static,
     {
         assert!(!base.is_null());
-        let dtor = Box::new(dtor);
         CVec {
             base,
             len,
-            dtor: Some(dtor),
+            dtor: Some(Dtor::Boxed(Box::new(dtor))),
+            reversed: false,
+            start: 0,
+            owns_elements: false,
+            #[cfg(feature = "debug-bounds")]
+            alloc_len: len,
         }
     }
 
-    /// Retrieves an element at a given index, returning [`None`] if the requested
-    /// index is greater than the length of the vector.
+    /// Create a `CVec` from a foreign buffer, with a given length,
+    /// and a function pointer to run upon destruction.
+    ///
+    /// Unlike [`CVec::new_with_dtor`], the destructor isn't boxed, which avoids
+    /// an allocation for `free`-like functions.
+    ///
+    /// Panics if the given pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must point to `len` initialized elements which remain valid until
+    /// `dtor` is called.
     ///
     /// # Example
     ///
     /// ```
     /// use c_vec::CVec;
     ///
+    /// fn free(_: *mut i32) {
+    ///     println!("free time!");
+    /// }
+    ///
     /// let slice = &mut [0, 1, 2];
     /// let ptr = slice.as_mut_ptr();
-    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
-    /// assert_eq!(cvec.get(1), slice.get(1));
+    /// let cvec = unsafe { CVec::new_with_fn(ptr, slice.len(), free) };
     /// ```
-    pub fn get(&self, ofs: usize) -> Option<&T> {
-        if ofs < self.len {
-            Some(unsafe { &*self.base.add(ofs) })
-        } else {
-            None
+    pub unsafe fn new_with_fn(base: *mut T, len: usize, dtor: fn(*mut T)) -> CVec<T> {
+        assert!(!base.is_null());
+        CVec {
+            base,
+            len,
+            dtor: Some(Dtor::Fn(dtor)),
+            reversed: false,
+            start: 0,
+            owns_elements: false,
+            #[cfg(feature = "debug-bounds")]
+            alloc_len: len,
         }
     }
 
-    /// Returns a reference to an element without doing any check.
+    /// Create a `CVec` from a foreign buffer, with a given length, kept alive
+    /// by `owner`. `owner` is dropped when the returned vector is dropped.
+    ///
+    /// Panics if the given pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must point to `len` initialized elements which remain valid as
+    /// long as `owner` is alive.
     ///
     /// # Example
     ///
     /// ```
     /// use c_vec::CVec;
+    /// use std::rc::Rc;
     ///
-    /// let slice = &mut [0, 1, 2];
-    /// let ptr = slice.as_mut_ptr();
-    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
-    /// unsafe {
-    ///     assert_eq!(cvec.get_unchecked(1), slice.get_unchecked(1));
-    /// }
+    /// let data = Rc::new([0, 1, 2]);
+    /// let ptr = data.as_ptr() as *mut i32;
+    /// let cvec = unsafe { CVec::new_with_owner(ptr, data.len(), Rc::clone(&data)) };
+    /// assert_eq!(Rc::strong_count(&data), 2);
+    /// drop(cvec);
+    /// assert_eq!(Rc::strong_count(&data), 1);
     /// ```
-    pub unsafe fn get_unchecked(&self, ofs: usize) -> &T {
-        &*self.base.add(ofs)
+    pub unsafe fn new_with_owner<O: 'static>(base: *mut T, len: usize, owner: O) -> CVec<T> {
+        CVec::new_with_dtor(base, len, move |_| drop(owner))
     }
 
-    /// Retrieves a mutable element at a given index, returning [`None`] if the
-    /// requested index is greater than the length of the vector.
+    /// Create a `CVec` over the same memory as `slice`, without destructor.
+    ///
+    /// # Safety
+    ///
+    /// The returned vector borrows the memory of `slice` without a lifetime: it
+    /// must not outlive the original buffer, and the buffer must not be
+    /// accessed through anything else while the vector is alive.
     ///
     /// # Example
     ///
     /// ```
-    /// use c_vec::CVec;
+    /// use c_vec::{CSliceMut, CVec};
     ///
     /// let slice = &mut [0, 1, 2];
     /// let ptr = slice.as_mut_ptr();
-    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
-    /// if let Some(el) = cvec.get_mut(1) {
-    ///     *el += 10;
-    /// }
-    /// assert_eq!(cvec[1], 11);
+    /// let cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// let cvec = unsafe { CVec::from_cslice_mut(cslice) };
+    /// assert_eq!(cvec[1], 1);
     /// ```
-    pub fn get_mut(&mut self, ofs: usize) -> Option<&mut T> {
-        if ofs < self.len {
-            Some(unsafe { &mut *self.base.add(ofs) })
-        } else {
-            None
-        }
+    pub unsafe fn from_cslice_mut(slice: CSliceMut<'_, T>) -> CVec<T> {
+        CVec::new(slice.base, slice.len)
     }
 
-    /// Returns a mutable reference to an element without doing any check.
+    /// Create a `CVec` from a raw pointer and a pointer to its length, as
+    /// returned by the common `void f(T **out_ptr, int *out_len)` C pattern.
+    ///
+    /// Panics if any of the given pointers is null or if the length is
+    /// negative. The returned vector will not attempt to deallocate the vector
+    /// when dropped.
+    ///
+    /// # Safety
+    ///
+    /// `len_ptr` must be valid for reads, and `base` must point to `*len_ptr`
+    /// initialized elements which remain valid for the whole lifetime of the
+    /// returned vector.
     ///
     /// # Example
     ///
@@ -237,42 +563,66 @@ impl<T> CVec<T> {
     /// use c_vec::CVec;
     ///
     /// let slice = &mut [0, 1, 2];
-    /// let ptr = slice.as_mut_ptr();
-    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
-    /// unsafe { *cvec.get_unchecked_mut(1) += 10; }
-    /// assert_eq!(cvec[1], 11);
+    /// let len: libc::c_int = 3;
+    /// let cvec = unsafe { CVec::from_out_params(slice.as_mut_ptr(), &len) };
+    /// assert_eq!(cvec.len(), 3);
     /// ```
-    pub unsafe fn get_unchecked_mut<'a>(&'a mut self, ofs: usize) -> &mut T {
-        &mut *self.base.add(ofs)
+    #[cfg(feature = "libc")]
+    pub unsafe fn from_out_params(base: *mut T, len_ptr: *const libc::c_int) -> CVec<T> {
+        assert!(!len_ptr.is_null());
+        assert!(*len_ptr >= 0);
+        CVec::new(base, *len_ptr as usize)
     }
 
-    /// Unwrap the pointer without running the destructor
+    /// Create a `CVec` from a raw pointer to a buffer with a given length,
+    /// unless `len` is equal to `sentinel`, in which case the length is
+    /// computed by calling `fallback` with `base`.
     ///
-    /// This method retrieves the underlying pointer, and in the process
-    /// destroys the CVec but without running the destructor. A use case
-    /// would be transferring ownership of the buffer to a C function, as
-    /// in this case you would not want to run the destructor.
+    /// Panics if the given pointer is null. The returned vector will not attempt
+    /// to deallocate the vector when dropped.
     ///
-    /// Note that if you want to access the underlying pointer without
-    /// cancelling the destructor, you can simply call `transmute` on the return
-    /// value of [`CVec::get`]`(0)`.
+    /// # Safety
+    ///
+    /// `base` must point to as many initialized elements as the resulting
+    /// length, which remain valid for the whole lifetime of the returned
+    /// vector.
     ///
     /// # Example
     ///
     /// ```
     /// use c_vec::CVec;
     ///
-    /// let slice = &mut [0, 1, 2];
+    /// let slice = &mut [1, 2, 0];
     /// let ptr = slice.as_mut_ptr();
-    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
-    /// assert_eq!(unsafe { cvec.into_inner() }, ptr);
+    /// let cvec = unsafe {
+    ///     CVec::with_sentinel_len(ptr, usize::MAX, usize::MAX, |ptr| {
+    ///         (0..).take_while(|&i| *ptr.add(i) != 0).count()
+    ///     })
+    /// };
+    /// assert_eq!(cvec.as_ref(), [1, 2]);
     /// ```
-    pub unsafe fn into_inner(mut self) -> *mut T {
-        self.dtor = None;
-        self.base
+    pub unsafe fn with_sentinel_len<F>(
+        base: *mut T,
+        len: usize,
+        sentinel: usize,
+        fallback: F,
+    ) -> CVec<T>
+    where
+        F: FnOnce(*mut T) -> usize,
+    {
+        assert!(!base.is_null());
+        let len = if len == sentinel { fallback(base) } else { len };
+        CVec::new(base, len)
     }
 
-    /// Returns the number of items in this vector.
+    /// Runs the destructor of the current buffer, if any, then makes the vector
+    /// point to a new buffer with a given length, without destructor.
+    ///
+    /// Panics if the given pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`CVec::new`].
     ///
     /// # Example
     ///
@@ -281,14 +631,23 @@ impl<T> CVec<T> {
     ///
     /// let slice = &mut [0, 1, 2];
     /// let ptr = slice.as_mut_ptr();
-    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
-    /// assert_eq!(cvec.len(), slice.len());
+    /// let mut cvec = unsafe { CVec::new_with_dtor(ptr, slice.len(), |_| println!("free time!")) };
+    /// let other = &mut [3, 4];
+    /// unsafe { cvec.reset(other.as_mut_ptr(), other.len()) };
+    /// assert_eq!(cvec.as_ref(), [3, 4]);
     /// ```
-    pub fn len(&self) -> usize {
-        self.len
+    pub unsafe fn reset(&mut self, base: *mut T, len: usize) {
+        *self = CVec::new(base, len);
     }
 
-    /// Returns whether this vector is empty.
+    /// Runs the destructor of the current buffer, if any, then makes the vector
+    /// point to a new buffer with a given length and a new destructor.
+    ///
+    /// Panics if the given pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`CVec::new_with_dtor`].
     ///
     /// # Example
     ///
@@ -297,34 +656,55 @@ impl<T> CVec<T> {
     ///
     /// let slice = &mut [0, 1, 2];
     /// let ptr = slice.as_mut_ptr();
-    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
-    /// assert_eq!(cvec.is_empty(), slice.is_empty());
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let other = &mut [3, 4];
+    /// unsafe { cvec.reset_with_dtor(other.as_mut_ptr(), other.len(), |_| println!("free time!")) };
+    /// assert_eq!(cvec.as_ref(), [3, 4]);
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub unsafe fn reset_with_dtor<F>(&mut self, base: *mut T, len: usize, dtor: F)
+    where
+        F: FnOnce(*mut T) + 'static,
+    {
+        *self = CVec::new_with_dtor(base, len, dtor);
     }
 
-    /// Returns a [`CSlice`] which is a "view" over the data.
+    /// Create a `CVec` from a foreign buffer, with a given length, and a
+    /// function to run upon destruction. Unlike [`CVec::new_with_dtor`], the
+    /// elements of the vector are dropped (see [`CVec::drop_elements`]) before
+    /// `dtor` is called.
+    ///
+    /// Panics if the given pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must point to `len` initialized elements owned by the returned
+    /// vector, which remain valid until `dtor` is called.
     ///
     /// # Example
     ///
     /// ```
     /// use c_vec::CVec;
+    /// use std::mem::ManuallyDrop;
     ///
-    /// let slice = &mut [0, 1, 2];
-    /// let ptr = slice.as_mut_ptr();
-    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
-    /// let cslice = cvec.as_cslice();
+    /// let mut v = ManuallyDrop::new(vec![String::from("a"), String::from("b")]);
+    /// let (ptr, cap) = (v.as_mut_ptr(), v.capacity());
+    /// let cvec = unsafe {
+    ///     CVec::new_with_element_drop(ptr, v.len(), move |ptr| {
+    ///         drop(Vec::from_raw_parts(ptr, 0, cap));
+    ///     })
+    /// };
     /// ```
-    pub fn as_cslice<'a>(&'a self) -> CSlice<'a, T> {
-        CSlice {
-            base: self.base,
-            len: self.len,
-            _phantom: PhantomData,
-        }
+    pub unsafe fn new_with_element_drop<F>(base: *mut T, len: usize, dtor: F) -> CVec<T>
+    where
+        F: FnOnce(*mut T) + 'static,
+    {
+        let mut cvec = CVec::new_with_dtor(base, len, dtor);
+        cvec.owns_elements = true;
+        cvec
     }
 
-    /// Returns a [`CSliceMut`] which is a mutable "view" over the data.
+    /// Retrieves an element at a given index, returning [`None`] if the requested
+    /// index is greater than the length of the vector.
     ///
     /// # Example
     ///
@@ -333,18 +713,19 @@ impl<T> CVec<T> {
     ///
     /// let slice = &mut [0, 1, 2];
     /// let ptr = slice.as_mut_ptr();
-    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
-    /// let cslice = cvec.as_cslice_mut();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.get(1), slice.get(1));
     /// ```
-    pub fn as_cslice_mut<'a>(&'a mut self) -> CSliceMut<'a, T> {
-        CSliceMut {
-            base: self.base,
-            len: self.len,
-            _phantom: PhantomData,
+    pub fn get(&self, ofs: usize) -> Option<&T> {
+        if ofs < self.len {
+            Some(unsafe { &*self.ptr_at(ofs) })
+        } else {
+            None
         }
     }
 
-    /// Returns an iterator over `CVec` data.
+    /// Retrieves the element at `ofs` modulo the length of the vector,
+    /// returning [`None`] only if the vector is empty.
     ///
     /// # Example
     ///
@@ -354,18 +735,15 @@ impl<T> CVec<T> {
     /// let slice = &mut [0, 1, 2];
     /// let ptr = slice.as_mut_ptr();
     /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
-    /// for elem in cvec.iter() {
-    ///     println!("=> {}", elem);
-    /// }
+    /// assert_eq!(cvec.get_wrapping(4), Some(&1));
     /// ```
-    pub fn iter<'a>(&'a self) -> CVecIter<'a, T> {
-        CVecIter {
-            inner: self,
-            pos: 0,
-        }
+    pub fn get_wrapping(&self, ofs: usize) -> Option<&T> {
+        ofs.checked_rem(self.len)
+            .map(|ofs| unsafe { &*self.ptr_at(ofs) })
     }
 
-    /// Returns a mutable iterator over `CVec` data.
+    /// Retrieves an element at a given index, returning `default` if the
+    /// requested index is greater than the length of the vector.
     ///
     /// # Example
     ///
@@ -374,9 +752,2270 @@ impl<T> CVec<T> {
     ///
     /// let slice = &mut [0, 1, 2];
     /// let ptr = slice.as_mut_ptr();
-    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
-    /// for elem in cvec.iter_mut() {
-    ///     *elem += 1;
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.get_or(1, &10), &1);
+    /// assert_eq!(cvec.get_or(3, &10), &10);
+    /// ```
+    pub fn get_or<'b>(&'b self, ofs: usize, default: &'b T) -> &'b T {
+        self.get(ofs).unwrap_or(default)
+    }
+
+    /// Returns a reference to an element without doing any check.
+    ///
+    /// # Safety
+    ///
+    /// `ofs` must be lower than the length of the vector. With the
+    /// `debug-bounds` feature, debug builds panic if `ofs` goes past the length
+    /// the vector was created with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// unsafe {
+    ///     assert_eq!(cvec.get_unchecked(1), slice.get_unchecked(1));
+    /// }
+    /// ```
+    pub unsafe fn get_unchecked(&self, ofs: usize) -> &T {
+        #[cfg(feature = "debug-bounds")]
+        debug_assert!(ofs < self.alloc_len);
+        &*self.ptr_at(ofs)
+    }
+
+    /// Retrieves a mutable element at a given index, returning [`None`] if the
+    /// requested index is greater than the length of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// if let Some(el) = cvec.get_mut(1) {
+    ///     *el += 10;
+    /// }
+    /// assert_eq!(cvec[1], 11);
+    /// ```
+    pub fn get_mut(&mut self, ofs: usize) -> Option<&mut T> {
+        if ofs < self.len {
+            Some(unsafe { &mut *self.ptr_at(ofs) })
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves a mutable element at `ofs` modulo the length of the vector,
+    /// returning [`None`] only if the vector is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// if let Some(el) = cvec.get_wrapping_mut(4) {
+    ///     *el += 10;
+    /// }
+    /// assert_eq!(cvec[1], 11);
+    /// ```
+    pub fn get_wrapping_mut(&mut self, ofs: usize) -> Option<&mut T> {
+        ofs.checked_rem(self.len)
+            .map(|ofs| unsafe { &mut *self.ptr_at(ofs) })
+    }
+
+    /// Returns a mutable reference to an element without doing any check.
+    ///
+    /// # Safety
+    ///
+    /// `ofs` must be lower than the length of the vector. With the
+    /// `debug-bounds` feature, debug builds panic if `ofs` goes past the length
+    /// the vector was created with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// unsafe { *cvec.get_unchecked_mut(1) += 10; }
+    /// assert_eq!(cvec[1], 11);
+    /// ```
+    pub unsafe fn get_unchecked_mut(&mut self, ofs: usize) -> &mut T {
+        #[cfg(feature = "debug-bounds")]
+        debug_assert!(ofs < self.alloc_len);
+        &mut *self.ptr_at(ofs)
+    }
+
+    /// Returns mutable references to the elements at the given indices at once,
+    /// returning [`None`] if any index is out of bounds or if two indices are
+    /// equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// if let Some([a, b]) = cvec.get_many_mut([0, 2]) {
+    ///     std::mem::swap(a, b);
+    /// }
+    /// assert_eq!(cvec[0], 2);
+    /// assert!(cvec.get_many_mut([1, 1]).is_none());
+    /// ```
+    pub fn get_many_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        for (pos, &idx) in indices.iter().enumerate() {
+            if idx >= self.len || indices[..pos].contains(&idx) {
+                return None;
+            }
+        }
+        Some(indices.map(|idx| unsafe { &mut *self.ptr_at(idx) }))
+    }
+
+    /// Unwrap the pointer without running the destructor
+    ///
+    /// This method retrieves the underlying pointer, and in the process
+    /// destroys the CVec but without running the destructor. A use case
+    /// would be transferring ownership of the buffer to a C function, as
+    /// in this case you would not want to run the destructor.
+    ///
+    /// Note that if you want to access the underlying pointer without
+    /// cancelling the destructor, you can simply call `transmute` on the return
+    /// value of [`CVec::get`]`(0)`.
+    ///
+    /// The length of the vector is lost: use [`CVec::into_ptr_len`] if you
+    /// need it as well.
+    ///
+    /// # Safety
+    ///
+    /// The destructor won't be run anymore so the caller becomes responsible
+    /// for freeing the buffer and dropping the elements if needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(unsafe { cvec.into_inner() }, ptr);
+    /// ```
+    pub unsafe fn into_inner(mut self) -> *mut T {
+        self.dtor = None;
+        self.owns_elements = false;
+        self.base
+    }
+
+    /// Destroys the `CVec` without running the destructor and returns its
+    /// underlying pointer and its length.
+    ///
+    /// # Safety
+    ///
+    /// The destructor won't be run anymore so the caller becomes responsible
+    /// for freeing the buffer and dropping the elements if needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(unsafe { cvec.into_ptr_len() }, (ptr, 3));
+    /// ```
+    pub unsafe fn into_ptr_len(mut self) -> (*mut T, usize) {
+        self.dtor = None;
+        self.owns_elements = false;
+        (self.base, self.len)
+    }
+
+    /// Gives back the `Vec` this vector was created from with `CVec::from`,
+    /// without copying and with its original capacity.
+    ///
+    /// Returns `None` if the vector doesn't come from a `Vec` (or if its
+    /// destructor was replaced since), in which case it is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let mut v = Vec::with_capacity(10);
+    /// v.extend_from_slice(&[0, 1, 2]);
+    /// let cvec = CVec::from(v);
+    /// assert_eq!(cvec.len(), 3);
+    /// let v = cvec.reclaim_vec().unwrap();
+    /// assert_eq!(v, [0, 1, 2]);
+    /// assert_eq!(v.capacity(), 10);
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert!(cvec.reclaim_vec().is_none());
+    /// ```
+    pub fn reclaim_vec(mut self) -> Option<Vec<T>> {
+        match self.dtor {
+            Some(Dtor::Vec(cap)) => {
+                self.dtor = None;
+                self.owns_elements = false;
+                Some(unsafe { Vec::from_raw_parts(self.base, self.len, cap) })
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns whether the vector has a destructor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new_with_dtor(ptr, slice.len(), |_| println!("free time!")) };
+    /// assert!(cvec.has_dtor());
+    /// ```
+    pub fn has_dtor(&self) -> bool {
+        self.dtor.is_some()
+    }
+
+    /// Removes the destructor of the vector and returns it. It won't be run
+    /// when the vector is dropped anymore.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new_with_dtor(ptr, slice.len(), |_| println!("free time!")) };
+    /// let dtor = cvec.take_dtor();
+    /// assert!(dtor.is_some());
+    /// assert!(!cvec.has_dtor());
+    /// ```
+    pub fn take_dtor(&mut self) -> Option<Box<dyn FnOnce(*mut T)>>
+    where
+        T: 'static,
+    {
+        self.dtor.take().map(Dtor::into_boxed)
+    }
+
+    /// Sets the destructor of the vector, replacing the current one without
+    /// running it.
+    ///
+    /// # Safety
+    ///
+    /// The buffer must remain valid until `dtor` is called.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// unsafe { cvec.set_dtor(|_| println!("free time!")) };
+    /// assert!(cvec.has_dtor());
+    /// ```
+    pub unsafe fn set_dtor<F>(&mut self, dtor: F)
+    where
+        F: FnOnce(*mut T) + 'static,
+    {
+        self.dtor = Some(Dtor::Boxed(Box::new(dtor)));
+    }
+
+    /// Sets the destructor of the vector and returns the previous one, which
+    /// isn't run.
+    ///
+    /// # Safety
+    ///
+    /// The buffer must remain valid until `dtor` is called.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new_with_dtor(ptr, slice.len(), |_| println!("first")) };
+    /// let first = unsafe { cvec.replace_dtor(|_| println!("second")) };
+    /// assert!(first.is_some());
+    /// ```
+    pub unsafe fn replace_dtor<F>(&mut self, dtor: F) -> Option<Box<dyn FnOnce(*mut T)>>
+    where
+        F: FnOnce(*mut T) + 'static,
+        T: 'static,
+    {
+        let old = self.take_dtor();
+        self.set_dtor(dtor);
+        old
+    }
+
+    /// Turns this vector into a [`Box`] without copying the elements. The
+    /// destructor isn't run: the box is now responsible for freeing the
+    /// buffer and dropping the elements.
+    ///
+    /// # Safety
+    ///
+    /// The buffer must have been allocated by Rust's global allocator with the
+    /// layout of a `[T]` of exactly `len` elements, for example by a `Box<[T]>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let boxed: Box<[i32]> = Box::new([0, 1, 2]);
+    /// let len = boxed.len();
+    /// let ptr = Box::into_raw(boxed) as *mut i32;
+    /// let cvec = unsafe { CVec::new(ptr, len) };
+    /// let boxed = unsafe { cvec.into_boxed_slice_unchecked() };
+    /// assert_eq!(*boxed, [0, 1, 2]);
+    /// ```
+    pub unsafe fn into_boxed_slice_unchecked(mut self) -> Box<[T]> {
+        self.assert_memory_order();
+        self.dtor = None;
+        self.owns_elements = false;
+        Box::from_raw(ptr::slice_from_raw_parts_mut(self.base, self.len))
+    }
+
+    /// Reinterprets the elements of this vector as `U` without copying them.
+    ///
+    /// The base pointer and the length are kept and the destructor (if any) is
+    /// transferred to the returned vector.
+    ///
+    /// Panics if `T` and `U` don't have the same size and alignment.
+    ///
+    /// # Safety
+    ///
+    /// Every element of the buffer must be a valid value of type `U`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0u32, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let cvec: CVec<i32> = unsafe { cvec.cast() };
+    /// assert_eq!(cvec[2], 2);
+    /// ```
+    pub unsafe fn cast<U>(mut self) -> CVec<U> {
+        assert_eq!(mem::size_of::<T>(), mem::size_of::<U>());
+        assert_eq!(mem::align_of::<T>(), mem::align_of::<U>());
+        let dtor = self.dtor.take().map(|f| match f {
+            // Functions only differing by the pointee type of their raw pointer
+            // argument are ABI-compatible, so the destructor can be reused
+            // without wrapping it (which would also require `T: 'static`).
+            Dtor::Boxed(f) => Dtor::Boxed(mem::transmute::<
+                Box<dyn FnOnce(*mut T)>,
+                Box<dyn FnOnce(*mut U)>,
+            >(f)),
+            Dtor::Fn(f) => Dtor::Fn(mem::transmute::<fn(*mut T), fn(*mut U)>(f)),
+            // `T` and `U` have the same layout so the allocation can be freed
+            // as a `Vec<U>`.
+            Dtor::Vec(cap) => Dtor::Vec(cap),
+        });
+        CVec {
+            base: self.base as *mut U,
+            len: self.len,
+            dtor,
+            reversed: self.reversed,
+            start: self.start,
+            owns_elements: mem::replace(&mut self.owns_elements, false),
+            #[cfg(feature = "debug-bounds")]
+            alloc_len: self.alloc_len,
+        }
+    }
+
+    /// Splits the vector into two vectors at the given index, each with its own
+    /// destructor. The first one contains the elements `[0, at)` and the second
+    /// one the elements `[at, len)`.
+    ///
+    /// The current destructor is dropped without being run. `free_head`
+    /// receives the base pointer of the first vector and `free_tail` the base
+    /// pointer of the second one. If this vector drops its elements, each half
+    /// drops its own ones.
+    ///
+    /// Panics if `at > len`.
+    ///
+    /// # Safety
+    ///
+    /// The two halves must be freeable independently: `free_head` and
+    /// `free_tail` must never free memory belonging to the other half, and
+    /// together they must release everything the previous destructor did.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let (head, tail) = unsafe { cvec.split_owned(1, |_| {}, |_| {}) };
+    /// assert_eq!(head.as_ref(), [0]);
+    /// assert_eq!(tail.as_ref(), [1, 2]);
+    /// ```
+    pub unsafe fn split_owned<F1, F2>(
+        mut self,
+        at: usize,
+        free_head: F1,
+        free_tail: F2,
+    ) -> (CVec<T>, CVec<T>)
+    where
+        F1: FnOnce(*mut T) + 'static,
+        F2: FnOnce(*mut T) + 'static,
+    {
+        self.assert_memory_order();
+        assert!(at <= self.len);
+        self.dtor = None;
+        let owns_elements = mem::replace(&mut self.owns_elements, false);
+        let mut head = CVec::new_with_dtor(self.base, at, free_head);
+        let mut tail = CVec::new_with_dtor(self.base.add(at), self.len - at, free_tail);
+        head.owns_elements = owns_elements;
+        tail.owns_elements = owns_elements;
+        (head, tail)
+    }
+
+    /// Turns this vector into a [`SharedCVec`], allowing multiple handles to
+    /// keep the buffer alive. The destructor runs when the last handle is
+    /// dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let shared = cvec.into_shared();
+    /// let head = shared.subslice(0..1);
+    /// let tail = shared.subslice(1..3);
+    /// ```
+    pub fn into_shared(self) -> SharedCVec<T> {
+        SharedCVec::new(self)
+    }
+
+    /// Returns a read-only handle over the vector. The vector can't be mutated
+    /// until the handle is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let frozen = cvec.freeze();
+    /// assert_eq!(frozen[1], 1);
+    /// ```
+    pub fn freeze(&mut self) -> FrozenCVec<'_, T> {
+        FrozenCVec::new(self)
+    }
+
+    /// Returns the number of items in this vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.len(), slice.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Sets the length of the vector without checking the buffer.
+    ///
+    /// Panics if the vector was created with [`CVec::new_reversed`] and
+    /// `new_len` is greater than the length it was created with.
+    ///
+    /// # Safety
+    ///
+    /// `new_len` elements must be initialized and fit in the buffer. The
+    /// length must not be changed while the vector is borrowed, including by
+    /// its iterators. A reversed vector keeps its first element in place and
+    /// shrinks towards it, so it can only grow back to the length it was
+    /// created with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, 1) };
+    /// unsafe { cvec.set_len(3) };
+    /// assert_eq!(cvec[2], 2);
+    /// ```
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        if self.reversed {
+            // The last element in memory is the first one and must not move.
+            self.start = (self.start + self.len)
+                .checked_sub(new_len)
+                .expect("a reversed `CVec` can't grow past its original length");
+        }
+        self.len = new_len;
+    }
+
+    /// Returns whether this vector is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.is_empty(), slice.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a description of the memory used by the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0u32, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let layout = cvec.layout();
+    /// assert_eq!(layout.byte_len, 12);
+    /// ```
+    pub fn layout(&self) -> BufferLayout {
+        BufferLayout::new(self.memory_start(), self.len)
+    }
+
+    /// Returns `true` if the whole vector lies inside the memory region of
+    /// `region_len` bytes starting at `region_start`, which is useful to check
+    /// that a foreign buffer really points into an expected arena.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let region = &mut [0u32; 8];
+    /// let cvec = unsafe { CVec::new(region.as_mut_ptr().add(2), 3) };
+    /// assert!(cvec.is_within(region.as_ptr() as *const u8, 32));
+    /// assert!(!cvec.is_within(region.as_ptr() as *const u8, 16));
+    /// ```
+    pub fn is_within(&self, region_start: *const u8, region_len: usize) -> bool {
+        self.layout().is_within(region_start, region_len)
+    }
+
+    /// Shortens the vector, keeping the first `new_len` elements.
+    ///
+    /// If `new_len` is greater than or equal to the current length, this has no
+    /// effect. The elements beyond `new_len` are not dropped and the destructor
+    /// will still receive the original base pointer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// cvec.truncate(2);
+    /// assert_eq!(cvec.len(), 2);
+    /// cvec.truncate(10);
+    /// assert_eq!(cvec.len(), 2);
+    /// ```
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len {
+            unsafe { self.set_len(new_len) };
+        }
+    }
+
+    /// Drops the elements of the vector in place and sets its length to 0. The
+    /// buffer itself is left to the destructor.
+    ///
+    /// # Safety
+    ///
+    /// The elements must be owned by the vector: nothing else may use or drop
+    /// them afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    /// use std::mem::ManuallyDrop;
+    ///
+    /// let mut v = ManuallyDrop::new(vec![String::from("a"), String::from("b")]);
+    /// let mut cvec = unsafe { CVec::new(v.as_mut_ptr(), v.len()) };
+    /// unsafe { cvec.drop_elements() };
+    /// assert!(cvec.is_empty());
+    /// unsafe { v.set_len(0) };
+    /// unsafe { ManuallyDrop::drop(&mut v) };
+    /// ```
+    pub unsafe fn drop_elements(&mut self) {
+        let len = self.len;
+        self.len = 0;
+        ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.memory_start(), len));
+    }
+
+    /// Returns a [`CSlice`] over the valid elements of the vector, which is to
+    /// say the elements before [`CVec::len`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// cvec.truncate(1);
+    /// assert_eq!(cvec.valid_slice().as_ref(), [0]);
+    /// ```
+    pub fn valid_slice(&self) -> CSlice<'_, T> {
+        self.as_cslice()
+    }
+
+    /// Returns a [`CSlice`] over the first `valid` elements of the vector, for
+    /// when only them are known to be initialized.
+    ///
+    /// Panics if `valid` is greater than the length of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.as_cslice_len(2).as_ref(), [0, 1]);
+    /// ```
+    pub fn as_cslice_len(&self, valid: usize) -> CSlice<'_, T> {
+        assert!(valid <= self.len);
+        self.as_cslice().prefix(valid)
+    }
+
+    /// Returns a [`CSliceMut`] over the first `valid` elements of the vector,
+    /// for when only them are known to be initialized.
+    ///
+    /// Panics if `valid` is greater than the length of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.as_cslice_mut_len(2).as_ref(), [0, 1]);
+    /// ```
+    pub fn as_cslice_mut_len(&mut self, valid: usize) -> CSliceMut<'_, T> {
+        self.assert_memory_order();
+        assert!(valid <= self.len);
+        CSliceMut {
+            base: self.base,
+            len: valid,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the index of the first element which differs between this vector
+    /// and `other`, or [`None`] if they are equal.
+    ///
+    /// If one is a prefix of the other, the length of the shorter one is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::{CSlice, CVec};
+    ///
+    /// let (a, b) = (&mut [0, 1, 2], &[0, 1]);
+    /// let a = unsafe { CVec::new(a.as_mut_ptr(), a.len()) };
+    /// let b = unsafe { CSlice::new(b.as_ptr(), b.len()) };
+    /// assert_eq!(a.diff_index(&b), Some(2));
+    /// ```
+    pub fn diff_index(&self, other: &CSlice<T>) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        match self.iter().zip(other.as_ref()).position(|(x, y)| x != y) {
+            Some(pos) => Some(pos),
+            None if self.len != other.len() => Some(cmp::min(self.len, other.len())),
+            None => None,
+        }
+    }
+
+    /// Returns whether the first `n` elements of this vector and `other` are
+    /// equal.
+    ///
+    /// Panics if any of them has less than `n` elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::{CSlice, CVec};
+    ///
+    /// let (a, b) = (&mut [0, 1, 2], &[0, 1]);
+    /// let a = unsafe { CVec::new(a.as_mut_ptr(), a.len()) };
+    /// let b = unsafe { CSlice::new(b.as_ptr(), b.len()) };
+    /// assert!(a.eq_prefix(&b, 2));
+    /// ```
+    pub fn eq_prefix(&self, other: &CSlice<T>, n: usize) -> bool
+    where
+        T: PartialEq,
+    {
+        assert!(n <= self.len);
+        self.iter().take(n).eq(&other.as_ref()[..n])
+    }
+
+    /// Returns whether the vector contains the same elements as `iter`, in the
+    /// same order, without collecting the iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert!(cvec.eq_iter(0..3));
+    /// ```
+    pub fn eq_iter<I>(&self, iter: I) -> bool
+    where
+        I: IntoIterator<Item = T>,
+        T: PartialEq,
+    {
+        let mut iter = iter.into_iter();
+        self.iter()
+            .all(|elem| iter.next().is_some_and(|other| *elem == other))
+            && iter.next().is_none()
+    }
+
+    /// Returns the index and the values of each position where the vector and
+    /// `other` differ, up to the length of the shorter one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::{CSlice, CVec};
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let other = &[0, 3];
+    /// let other = unsafe { CSlice::new(other.as_ptr(), other.len()) };
+    /// assert_eq!(cvec.diff(&other), [(1, 1, 3)]);
+    /// ```
+    pub fn diff(&self, other: &CSlice<'_, T>) -> Vec<(usize, T, T)>
+    where
+        T: PartialEq + Clone,
+    {
+        self.with_logical_cslice(|cslice| cslice.diff(other))
+    }
+
+    /// Calls `f` on each consecutive [`CSlice`] of `chunk` elements. The last
+    /// one is shorter if the length isn't a multiple of `chunk`.
+    ///
+    /// Panics if `chunk` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// cvec.for_each_chunk(2, |chunk| println!("=> {:?}", chunk.as_ref()));
+    /// ```
+    pub fn for_each_chunk<F>(&self, chunk: usize, f: F)
+    where
+        F: FnMut(CSlice<T>),
+    {
+        self.as_cslice().for_each_chunk(chunk, f)
+    }
+
+    /// Calls `f` on each element along with its left and right neighbors
+    /// ([`None`] at the edges of the vector) and collects the results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let sums = cvec.map_neighbors(|l, x, r| l.unwrap_or(&0) + x + r.unwrap_or(&0));
+    /// assert_eq!(sums, [3, 6, 5]);
+    /// ```
+    pub fn map_neighbors<F>(&self, mut f: F) -> Vec<T>
+    where
+        F: FnMut(Option<&T>, &T, Option<&T>) -> T,
+    {
+        (0..self.len)
+            .map(|pos| {
+                let left = if pos > 0 { self.get(pos - 1) } else { None };
+                f(left, &self[pos], self.get(pos + 1))
+            })
+            .collect()
+    }
+
+    /// Fills the vector by repeating `pattern`. The last repetition is cut
+    /// if the length isn't a multiple of the pattern length.
+    ///
+    /// Panics if `pattern` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0; 5];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// cvec.fill_pattern(&[1, 2]);
+    /// assert_eq!(cvec.as_ref(), [1, 2, 1, 2, 1]);
+    /// ```
+    pub fn fill_pattern(&mut self, pattern: &[T])
+    where
+        T: Copy,
+    {
+        assert!(!pattern.is_empty());
+        for (dst, src) in self.iter_mut().zip(pattern.iter().cycle()) {
+            *dst = *src;
+        }
+    }
+
+    /// Writes clones of `value` into the elements from `valid` to the end of
+    /// the vector, typically after a foreign call initialized only the
+    /// first `valid` ones. The overwritten elements are considered as
+    /// uninitialized so they aren't dropped.
+    ///
+    /// Panics if `valid` is greater than the length of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [1, 2, 3, 4];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// cvec.init_tail(1, 0);
+    /// assert_eq!(cvec.as_ref(), [1, 0, 0, 0]);
+    /// ```
+    pub fn init_tail(&mut self, valid: usize, value: T)
+    where
+        T: Clone,
+    {
+        assert!(valid <= self.len);
+        for ofs in valid..self.len {
+            unsafe { ptr::write(self.ptr_at(ofs), value.clone()) };
+        }
+    }
+
+    /// Calls `f` on each element which has both a left and a right neighbor,
+    /// passing copies of the original value of these neighbors (not the ones
+    /// already updated by `f`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [1, 2, 3, 4];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// cvec.update_with_neighbors(|x, l, r| *x += l + r);
+    /// assert_eq!(cvec.as_ref(), [1, 6, 9, 4]);
+    /// ```
+    pub fn update_with_neighbors<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T, &T, &T),
+        T: Copy,
+    {
+        if self.len < 3 {
+            return;
+        }
+        let mut left = self[0];
+        for pos in 1..self.len - 1 {
+            let (current, right) = (self[pos], self[pos + 1]);
+            f(&mut self[pos], &left, &right);
+            left = current;
+        }
+    }
+
+    /// Returns the number of elements of the vector matching `pred`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.count(|x| x % 2 == 1), 2);
+    /// ```
+    pub fn count<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().filter(|x| pred(x)).count()
+    }
+
+    /// Returns whether all the elements of the vector match `pred`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert!(cvec.all(|x| *x > 0));
+    /// ```
+    pub fn all<P>(&self, pred: P) -> bool
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().all(pred)
+    }
+
+    /// Returns whether any element of the vector matches `pred`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert!(cvec.any(|x| *x == 2));
+    /// ```
+    pub fn any<P>(&self, pred: P) -> bool
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().any(pred)
+    }
+
+    /// Returns a view over the vector as rows of `W` elements, or [`None`] if
+    /// the length isn't a multiple of `W`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2, 3, 4, 5];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let rows = cvec.as_rows::<3>().unwrap();
+    /// assert_eq!(rows[1], [3, 4, 5]);
+    /// assert!(cvec.as_rows::<4>().is_none());
+    /// ```
+    pub fn as_rows<const W: usize>(&self) -> Option<&[[T; W]]> {
+        self.assert_memory_order();
+        if W == 0 || !self.len.is_multiple_of(W) {
+            return None;
+        }
+        Some(unsafe { slice::from_raw_parts(self.base as *const [T; W], self.len / W) })
+    }
+
+    /// Returns a mutable view over the vector as rows of `W` elements, or
+    /// [`None`] if the length isn't a multiple of `W`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2, 3, 4, 5];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// cvec.as_rows_mut::<2>().unwrap()[2] = [0, 0];
+    /// assert_eq!(cvec[4], 0);
+    /// ```
+    pub fn as_rows_mut<const W: usize>(&mut self) -> Option<&mut [[T; W]]> {
+        self.assert_memory_order();
+        if W == 0 || !self.len.is_multiple_of(W) {
+            return None;
+        }
+        Some(unsafe { slice::from_raw_parts_mut(self.base as *mut [T; W], self.len / W) })
+    }
+
+    /// Returns the maximum element of the vector, or [`None`] if it's empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [3, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.max(), Some(&3));
+    /// ```
+    pub fn max(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().max()
+    }
+
+    /// Returns the minimum element of the vector, or [`None`] if it's empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [3, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.min(), Some(&1));
+    /// ```
+    pub fn min(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().min()
+    }
+
+    /// Returns the sum of the elements of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [3, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.sum(), 6);
+    /// ```
+    pub fn sum(&self) -> T
+    where
+        T: Sum + Copy,
+    {
+        self.iter().copied().sum()
+    }
+
+    /// Rotates the vector in-place so that the element at `mid` becomes the
+    /// first one. No allocation is performed.
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// cvec.rotate_left_inplace(1);
+    /// assert_eq!(cvec.as_ref(), [1, 2, 3, 0]);
+    /// ```
+    pub fn rotate_left_inplace(&mut self, mid: usize) {
+        // Rotating a reversed vector to the left rotates its memory to the right.
+        if self.reversed {
+            self.as_memory_cslice_mut().rotate_right_inplace(mid)
+        } else {
+            self.as_cslice_mut().rotate_left_inplace(mid)
+        }
+    }
+
+    /// Rotates the vector in-place so that the last `k` elements become the
+    /// first ones. No allocation is performed.
+    ///
+    /// Panics if `k > len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// cvec.rotate_right_inplace(1);
+    /// assert_eq!(cvec.as_ref(), [3, 0, 1, 2]);
+    /// ```
+    pub fn rotate_right_inplace(&mut self, k: usize) {
+        if self.reversed {
+            self.as_memory_cslice_mut().rotate_left_inplace(k)
+        } else {
+            self.as_cslice_mut().rotate_right_inplace(k)
+        }
+    }
+
+    /// Copies all the elements from `src` into the vector.
+    ///
+    /// Panics if `src` doesn't have the same length as the vector. `src` is
+    /// expected not to overlap with the vector: debug builds panic if it does
+    /// while release builds fall back to an overlapping copy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0; 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// cvec.copy_from_slice(&[1, 2, 3]);
+    /// assert_eq!(cvec.as_ref(), [1, 2, 3]);
+    /// ```
+    pub fn copy_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        if self.reversed {
+            assert_eq!(self.len, src.len());
+            for (dst, src) in self.iter_mut().zip(src) {
+                *dst = *src;
+            }
+        } else {
+            self.as_cslice_mut().copy_from_slice(src)
+        }
+    }
+
+    /// Overwrites the elements starting at `ofs` with `data`. The original
+    /// elements are restored when the returned guard is dropped.
+    ///
+    /// Panics if `data` doesn't fit in the vector starting at `ofs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// drop(cvec.scoped_overwrite(0, &[3, 4]));
+    /// assert_eq!(cvec.as_ref(), [0, 1, 2]);
+    /// ```
+    pub fn scoped_overwrite(&mut self, ofs: usize, data: &[T]) -> RestoreGuard<'_, T>
+    where
+        T: Copy,
+    {
+        RestoreGuard::new(self.as_cslice_mut(), ofs, data)
+    }
+
+    /// Reorders the vector so that the element at `index` is at its final
+    /// sorted position, with smaller or equal elements before it and greater
+    /// or equal elements after it. See [`slice::select_nth_unstable`].
+    ///
+    /// Returns the elements before `index`, the element at `index` and the
+    /// elements after it.
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [4, 0, 2, 3, 1];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let (_, median, _) = cvec.select_nth_unstable(2);
+    /// assert_eq!(*median, 2);
+    /// ```
+    pub fn select_nth_unstable(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T])
+    where
+        T: Ord,
+    {
+        self.assert_memory_order();
+        self.as_mut().select_nth_unstable(index)
+    }
+
+    /// Same as [`CVec::select_nth_unstable`] but uses `compare` to order the
+    /// elements.
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [4, 0, 2, 3, 1];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let (_, elem, _) = cvec.select_nth_unstable_by(1, |a, b| b.cmp(a));
+    /// assert_eq!(*elem, 3);
+    /// ```
+    pub fn select_nth_unstable_by<F>(
+        &mut self,
+        index: usize,
+        compare: F,
+    ) -> (&mut [T], &mut T, &mut [T])
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.assert_memory_order();
+        self.as_mut().select_nth_unstable_by(index, compare)
+    }
+
+    /// Folds every element of the vector into an accumulator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.fold(1, |acc, x| acc * x), 6);
+    /// ```
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        self.iter().fold(init, f)
+    }
+
+    /// Calls `f` on each element of the vector, stopping at the first error,
+    /// which is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let res = cvec.try_for_each(|x| if *x < 2 { Ok(()) } else { Err(*x) });
+    /// assert_eq!(res, Err(2));
+    /// ```
+    pub fn try_for_each<E, F>(&self, f: F) -> Result<(), E>
+    where
+        F: FnMut(&T) -> Result<(), E>,
+    {
+        self.iter().try_for_each(f)
+    }
+
+    /// Calls `f` on each element of the vector, stopping at the first error,
+    /// which is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let res = cvec.try_for_each_mut(|x| {
+    ///     *x += 1;
+    ///     if *x < 2 { Ok(()) } else { Err(*x) }
+    /// });
+    /// assert_eq!(res, Err(2));
+    /// assert_eq!(cvec.as_ref(), [1, 2, 2]);
+    /// ```
+    pub fn try_for_each_mut<E, F>(&mut self, f: F) -> Result<(), E>
+    where
+        F: FnMut(&mut T) -> Result<(), E>,
+    {
+        self.iter_mut().try_for_each(f)
+    }
+
+    /// Returns a [`Vec`] alternating the elements of this vector and `other`,
+    /// starting with the first element of this vector.
+    ///
+    /// Panics if they don't have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::{CSlice, CVec};
+    ///
+    /// let (a, b) = (&mut [0, 2], &[1, 3]);
+    /// let a = unsafe { CVec::new(a.as_mut_ptr(), a.len()) };
+    /// let b = unsafe { CSlice::new(b.as_ptr(), b.len()) };
+    /// assert_eq!(a.interleave(&b), [0, 1, 2, 3]);
+    /// ```
+    pub fn interleave(&self, other: &CSlice<T>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.with_logical_cslice(|cslice| cslice.interleave(other))
+    }
+
+    /// Splits the elements of the vector into two [`Vec`]s: the first one gets
+    /// the elements at even indices and the second one the elements at odd
+    /// indices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2, 3, 4];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.deinterleave(), (vec![0, 2, 4], vec![1, 3]));
+    /// ```
+    pub fn deinterleave(&self) -> (Vec<T>, Vec<T>)
+    where
+        T: Clone,
+    {
+        self.with_logical_cslice(|cslice| cslice.deinterleave())
+    }
+
+    /// Returns whether all the bytes of the vector are zero.
+    ///
+    /// # Safety
+    ///
+    /// `T` must not contain padding bytes, as they may be uninitialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0u32; 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert!(unsafe { cvec.is_zeroed() });
+    /// ```
+    pub unsafe fn is_zeroed(&self) -> bool
+    where
+        T: Copy,
+    {
+        self.as_memory_cslice().is_zeroed()
+    }
+
+    /// Returns a [`CSlice`] which is a "view" over the data.
+    ///
+    /// Panics if the vector was created with [`CVec::new_reversed`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let cslice = cvec.as_cslice();
+    /// ```
+    pub fn as_cslice<'a>(&'a self) -> CSlice<'a, T> {
+        self.assert_memory_order();
+        CSlice {
+            base: self.base,
+            len: self.len,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a [`CSlice`] over the data in memory order. It is the same as
+    /// [`CVec::as_cslice`] except for a vector created with
+    /// [`CVec::new_reversed`], where the elements are in the opposite order
+    /// compared to indexing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let end = unsafe { slice.as_mut_ptr().add(2) };
+    /// let cvec = unsafe { CVec::new_reversed(end, slice.len()) };
+    /// assert_eq!(cvec[0], 2);
+    /// assert_eq!(cvec.as_memory_cslice().as_ref(), [0, 1, 2]);
+    /// ```
+    pub fn as_memory_cslice(&self) -> CSlice<'_, T> {
+        CSlice {
+            base: self.memory_start(),
+            len: self.len,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a [`CSlice`] over the first `n` elements, or over the whole
+    /// vector if it has less than `n` elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.prefix(2).as_ref(), [0, 1]);
+    /// ```
+    pub fn prefix(&self, n: usize) -> CSlice<'_, T> {
+        self.as_cslice().prefix(n)
+    }
+
+    /// Returns a [`CSlice`] over the last `n` elements, or over the whole
+    /// vector if it has less than `n` elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.suffix(2).as_ref(), [1, 2]);
+    /// ```
+    pub fn suffix(&self, n: usize) -> CSlice<'_, T> {
+        self.as_cslice().suffix(n)
+    }
+
+    /// Returns the parts of the vector before and after `range`, excluding the
+    /// range itself.
+    ///
+    /// Panics if the range is out of bounds or if its start is after its end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let (before, after) = cvec.without(1..3);
+    /// assert_eq!(before.as_ref(), [0]);
+    /// assert_eq!(after.as_ref(), [3]);
+    /// ```
+    pub fn without<R: RangeBounds<usize>>(&self, range: R) -> (CSlice<'_, T>, CSlice<'_, T>) {
+        self.as_cslice().without(range)
+    }
+
+    /// Returns a [`CSlice`] over the elements from `start` to `end` (excluded).
+    /// Both bounds are clamped to the length of the vector and an empty slice
+    /// is returned if `start` is after `end`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.saturating_slice(1, 10).as_ref(), [1, 2]);
+    /// assert!(cvec.saturating_slice(2, 1).is_empty());
+    /// ```
+    pub fn saturating_slice(&self, start: usize, end: usize) -> CSlice<'_, T> {
+        self.as_cslice().saturating_slice(start, end)
+    }
+
+    /// Splits the vector into two halves of the same length, or returns
+    /// [`None`] if its length is odd.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let (left, right) = cvec.split_even().unwrap();
+    /// assert_eq!(left.as_ref(), [0, 1]);
+    /// assert_eq!(right.as_ref(), [2, 3]);
+    /// ```
+    pub fn split_even(&self) -> Option<(CSlice<'_, T>, CSlice<'_, T>)> {
+        self.as_cslice().split_even()
+    }
+
+    /// Returns a [`CSlice`] over the leading elements satisfying `pred`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 2, 3, 4];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.take_while_view(|x| x % 2 == 0).as_ref(), [0, 2]);
+    /// ```
+    pub fn take_while_view<P: FnMut(&T) -> bool>(&self, pred: P) -> CSlice<'_, T> {
+        self.as_cslice().take_while_view(pred)
+    }
+
+    /// Returns a [`CSlice`] over the elements remaining after the leading ones
+    /// satisfying `pred`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 2, 3, 4];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.skip_while_view(|x| x % 2 == 0).as_ref(), [3, 4]);
+    /// ```
+    pub fn skip_while_view<P: FnMut(&T) -> bool>(&self, pred: P) -> CSlice<'_, T> {
+        self.as_cslice().skip_while_view(pred)
+    }
+
+    /// Splits the vector into two mutable halves of the same length, or returns
+    /// [`None`] if its length is odd.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let (mut left, right) = cvec.split_even_mut().unwrap();
+    /// left.copy_from_slice(right.as_ref());
+    /// assert_eq!(cvec.as_ref(), [2, 3, 2, 3]);
+    /// ```
+    pub fn split_even_mut(&mut self) -> Option<(CSliceMut<'_, T>, CSliceMut<'_, T>)> {
+        self.assert_memory_order();
+        if !self.len.is_multiple_of(2) {
+            return None;
+        }
+        let half = self.len / 2;
+        Some((
+            CSliceMut {
+                base: self.base,
+                len: half,
+                _phantom: PhantomData,
+            },
+            CSliceMut {
+                base: unsafe { self.base.add(half) },
+                len: half,
+                _phantom: PhantomData,
+            },
+        ))
+    }
+
+    /// Splits the vector into `n` interleaved channels: the channel `c` goes
+    /// over the elements at `c`, `c + n`, `c + 2 * n`, etc.
+    ///
+    /// Panics if `n` is 0 or if the length of the vector isn't a multiple of
+    /// `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [1, 10, 2, 20];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// for (gain, channel) in cvec.channels_mut(2).into_iter().enumerate() {
+    ///     for sample in channel {
+    ///         *sample *= gain + 1;
+    ///     }
+    /// }
+    /// assert_eq!(cvec.as_ref(), [1, 20, 2, 40]);
+    /// ```
+    pub fn channels_mut(&mut self, n: usize) -> Vec<ChannelView<'_, T>> {
+        self.assert_memory_order();
+        channel::channels(self.base, self.len, n)
+    }
+
+    /// Splits the vector into a prefix, a middle part correctly aligned for `U`
+    /// and a suffix, like [`slice::align_to`].
+    ///
+    /// # Safety
+    ///
+    /// Same as [`slice::align_to`]: any bit pattern of the middle part must be
+    /// a valid `U`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0u8; 9];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let (head, middle, tail) = unsafe { cvec.align_to::<u16>() };
+    /// assert_eq!(head.len() + middle.len() * 2 + tail.len(), 9);
+    /// ```
+    pub unsafe fn align_to<U>(&self) -> (CSlice<'_, T>, CSlice<'_, U>, CSlice<'_, T>) {
+        self.as_cslice().align_to()
+    }
+
+    /// Returns the index of `elem` if it points to an element of the vector,
+    /// or [`None`] otherwise (or if `T` is zero-sized).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.offset_of(&cvec[2]), Some(2));
+    /// assert_eq!(cvec.offset_of(&0), None);
+    /// ```
+    pub fn offset_of(&self, elem: &T) -> Option<usize> {
+        let memory = CSlice {
+            base: self.memory_start() as *const T,
+            len: self.len,
+            _phantom: PhantomData,
+        };
+        let ofs = memory.offset_of(elem)?;
+        Some(if self.reversed {
+            self.len - 1 - ofs
+        } else {
+            ofs
+        })
+    }
+
+    /// Copies the vector into a new `Vec` of length `total`, filling the
+    /// remaining elements with `pad`.
+    ///
+    /// Panics if `total` is smaller than the length of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.to_vec_padded(5, 9), [0, 1, 2, 9, 9]);
+    /// ```
+    pub fn to_vec_padded(&self, total: usize, pad: T) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.with_logical_cslice(|cslice| cslice.to_vec_padded(total, pad))
+    }
+
+    /// Copies the vector into a new `Vec`, in reverse order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.to_vec_reversed(), [2, 1, 0]);
+    /// ```
+    pub fn to_vec_reversed(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.with_logical_cslice(|cslice| cslice.to_vec_reversed())
+    }
+
+    /// Copies the vector into a new `Vec`, skipping consecutive duplicates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 0, 1, 0];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.to_vec_dedup(), [0, 1, 0]);
+    /// ```
+    pub fn to_vec_dedup(&self) -> Vec<T>
+    where
+        T: PartialEq + Clone,
+    {
+        self.with_logical_cslice(|cslice| cslice.to_vec_dedup())
+    }
+
+    /// Copies the vector into a new sorted `Vec` without duplicates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 0, 1, 0];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.to_vec_dedup_sorted(), [0, 1]);
+    /// ```
+    pub fn to_vec_dedup_sorted(&self) -> Vec<T>
+    where
+        T: Ord + Clone,
+    {
+        self.as_memory_cslice().to_vec_dedup_sorted()
+    }
+
+    /// Returns the runs of equal consecutive elements of the vector, along with
+    /// their length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 0, 1];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.rle(), [(0, 2), (1, 1)]);
+    /// ```
+    pub fn rle(&self) -> Vec<(T, usize)>
+    where
+        T: PartialEq + Clone,
+    {
+        self.with_logical_cslice(|cslice| cslice.rle())
+    }
+
+    /// Replaces the content of `target` with a copy of the vector, reusing its
+    /// allocation when possible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let mut v = vec![5; 10];
+    /// cvec.clone_into(&mut v);
+    /// assert_eq!(v, [0, 1, 2]);
+    /// ```
+    pub fn clone_into(&self, target: &mut Vec<T>)
+    where
+        T: Clone,
+    {
+        self.with_logical_cslice(|cslice| cslice.clone_into(target))
+    }
+
+    /// Copies the vector into `dst` and returns it, now initialized.
+    ///
+    /// Panics if `dst` doesn't have the same length as the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let mut dst = [MaybeUninit::uninit(); 3];
+    /// assert_eq!(cvec.copy_to_uninit(&mut dst), [0, 1, 2]);
+    /// ```
+    pub fn copy_to_uninit<'d>(&self, dst: &'d mut [MaybeUninit<T>]) -> &'d mut [T]
+    where
+        T: Copy,
+    {
+        if !self.reversed {
+            return self.as_cslice().copy_to_uninit(dst);
+        }
+        assert_eq!(dst.len(), self.len);
+        for (dst, src) in dst.iter_mut().zip(self.iter()) {
+            dst.write(*src);
+        }
+        unsafe { slice::from_raw_parts_mut(dst.as_mut_ptr() as *mut T, self.len) }
+    }
+
+    /// Copies the elements of the vector into two new `Vec`s: the first one
+    /// contains the elements for which `pred` returns `true`, the second one
+    /// the others.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.partition_copy(|x| *x > 0), (vec![1, 2], vec![0]));
+    /// ```
+    pub fn partition_copy<P>(&self, pred: P) -> (Vec<T>, Vec<T>)
+    where
+        P: FnMut(&T) -> bool,
+        T: Clone,
+    {
+        self.with_logical_cslice(|cslice| cslice.partition_copy(pred))
+    }
+
+    /// Returns a [`CSliceMut`] which is a mutable "view" over the data.
+    ///
+    /// Panics if the vector was created with [`CVec::new_reversed`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let cslice = cvec.as_cslice_mut();
+    /// ```
+    pub fn as_cslice_mut<'a>(&'a mut self) -> CSliceMut<'a, T> {
+        self.assert_memory_order();
+        CSliceMut {
+            base: self.base,
+            len: self.len,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a [`CSliceMut`] over the data in memory order. It is the same as
+    /// [`CVec::as_cslice_mut`] except for a vector created with
+    /// [`CVec::new_reversed`], where the elements are in the opposite order
+    /// compared to indexing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let end = unsafe { slice.as_mut_ptr().add(2) };
+    /// let mut cvec = unsafe { CVec::new_reversed(end, slice.len()) };
+    /// cvec.as_memory_cslice_mut().as_mut()[0] = 3;
+    /// assert_eq!(cvec[2], 3);
+    /// ```
+    pub fn as_memory_cslice_mut(&mut self) -> CSliceMut<'_, T> {
+        CSliceMut {
+            base: self.memory_start(),
+            len: self.len,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a [`CSliceMut`] over the data whose lifetime is tied to `_guard`
+    /// instead of a borrow of the vector.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the vector isn't dropped, truncated nor moved
+    /// out of for as long as the returned view (or any view derived from it) is
+    /// alive, and that no other reference to the data is used while it is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let guard = ();
+    /// let mut cslice = unsafe { cvec.as_cslice_mut_bound(&guard) };
+    /// *cslice.get_mut(0).unwrap() = 3;
+    /// assert_eq!(cvec[0], 3);
+    /// ```
+    pub unsafe fn as_cslice_mut_bound<'g>(&self, _guard: &'g ()) -> CSliceMut<'g, T> {
+        self.assert_memory_order();
+        CSliceMut {
+            base: self.base,
+            len: self.len,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Calls `f` with a [`CSliceMut`] over the data and returns its result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let len = cvec.with_slice_mut(|mut cslice| {
+    ///     cslice[0] = 10;
+    ///     cslice.len()
+    /// });
+    /// assert_eq!(len, 3);
+    /// assert_eq!(cvec[0], 10);
+    /// ```
+    pub fn with_slice_mut<R, F>(&mut self, f: F) -> R
+    where
+        F: FnOnce(CSliceMut<T>) -> R,
+    {
+        f(self.as_cslice_mut())
+    }
+
+    /// Returns a pinned mutable slice over the data.
+    ///
+    /// The buffer of a `CVec` is never moved by the `CVec` itself: moving the
+    /// `CVec` only moves the pointer to it, which is why `CVec` is always
+    /// [`Unpin`]. The elements therefore stay at the same address until the
+    /// destructor runs.
+    ///
+    /// # Safety
+    ///
+    /// Once pinned, elements which aren't [`Unpin`] must not be moved anymore,
+    /// including through the other mutable accessors of the `CVec` (like
+    /// [`AsMut`] or [`CVec::iter_mut`]), and the buffer must not be freed
+    /// before they are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    /// use std::pin::Pin;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let mut pinned = unsafe { Pin::new(&mut cvec).as_pinned_mut_slice() };
+    /// pinned[0] = 10;
+    /// assert_eq!(cvec[0], 10);
+    /// ```
+    pub unsafe fn as_pinned_mut_slice(self: Pin<&mut Self>) -> Pin<&mut [T]> {
+        let this = self.get_mut();
+        this.assert_memory_order();
+        Pin::new_unchecked(this.as_mut())
+    }
+
+    /// Returns an iterator over `CVec` data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// for elem in cvec.iter() {
+    ///     println!("=> {}", elem);
+    /// }
+    /// ```
+    pub fn iter<'a>(&'a self) -> CVecIter<'a, T> {
+        CVecIter {
+            inner: self,
+            pos: 0,
+            end: self.len,
+            len: self.len,
+        }
+    }
+
+    /// Returns an iterator over copies of the `CVec` elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.iter_copied().collect::<Vec<_>>(), [0, 1, 2]);
+    /// ```
+    pub fn iter_copied(&self) -> Copied<CVecIter<'_, T>>
+    where
+        T: Copy,
+    {
+        self.iter().copied()
+    }
+
+    /// Returns a [`slice::Iter`] over the `CVec` data, in memory order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.std_iter().rev().collect::<Vec<_>>(), [&2, &1, &0]);
+    /// ```
+    pub fn std_iter(&self) -> slice::Iter<'_, T> {
+        self.as_cslice().as_slice().iter()
+    }
+
+    /// Returns a [`slice::IterMut`] over the `CVec` data, in memory order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// cvec.std_iter_mut().for_each(|x| *x += 1);
+    /// assert_eq!(cvec.as_ref(), [1, 2, 3]);
+    /// ```
+    pub fn std_iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.assert_memory_order();
+        self.as_mut().iter_mut()
+    }
+
+    /// Returns an iterator over `CVec` data going from the last element to the
+    /// first one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let mut iter = cvec.iter_rev();
+    /// assert_eq!(iter.next(), Some(&2));
+    /// ```
+    pub fn iter_rev(&self) -> Rev<CVecIter<'_, T>> {
+        self.iter().rev()
+    }
+
+    /// Returns an iterator over the sub-slices separated by elements matching
+    /// `pred`. The separators aren't included in the sub-slices, and consecutive
+    /// separators (or one at either end) produce empty sub-slices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let mut iter = cvec.split(|x| *x == 1);
+    /// assert_eq!(iter.next().unwrap().as_ref(), [0]);
+    /// assert_eq!(iter.next().unwrap().as_ref(), [2]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn split<P>(&self, pred: P) -> CSliceSplit<'_, T, P>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_cslice().split(pred)
+    }
+
+    /// Clones the data into a [`Vec`] and returns its owning iterator. The
+    /// destructor runs before this method returns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let mut iter = cvec.into_iter_owned();
+    /// assert_eq!(iter.next(), Some(0));
+    /// assert_eq!(iter.as_slice(), [1, 2]);
+    /// ```
+    pub fn into_iter_owned(self) -> std::vec::IntoIter<T>
+    where
+        T: Clone,
+    {
+        Vec::from(self).into_iter()
+    }
+
+    /// Returns an iterator yielding the elements from the last one to the
+    /// first one. If the vector owns its elements (like when created from a
+    /// [`Vec`]), they are moved out, otherwise they are cloned. The remaining
+    /// elements are dropped and the destructor runs when the iterator is
+    /// dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.into_iter_rev().collect::<Vec<_>>(), [2, 1, 0]);
+    /// ```
+    pub fn into_iter_rev(self) -> CVecIntoIterRev<T>
+    where
+        T: Clone,
+    {
+        CVecIntoIterRev { inner: self }
+    }
+
+    /// Returns an iterator over copies of consecutive groups of `N` elements.
+    /// If the length isn't a multiple of `N`, the remaining elements are
+    /// skipped.
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2, 3, 4];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let mut iter = cvec.array_chunks::<2>();
+    /// assert_eq!(iter.next(), Some([0, 1]));
+    /// ```
+    pub fn array_chunks<const N: usize>(&self) -> impl Iterator<Item = [T; N]> + '_
+    where
+        T: Copy,
+    {
+        assert!(N != 0);
+        (0..self.len / N).map(move |chunk| array::from_fn(|pos| self[chunk * N + pos]))
+    }
+
+    /// Returns an iterator over the overlapping pairs of consecutive elements:
+    /// `(&self[0], &self[1])`, `(&self[1], &self[2])`, etc. Yields nothing if
+    /// the vector has less than 2 elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let mut iter = cvec.pairwise();
+    /// assert_eq!(iter.next(), Some((&0, &1)));
+    /// ```
+    pub fn pairwise(&self) -> impl Iterator<Item = (&T, &T)> + '_ {
+        (1..self.len).map(move |pos| (&self[pos - 1], &self[pos]))
+    }
+
+    /// Returns an iterator over the indices of the elements matching `pred`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let indices = cvec.match_indices(|x| x % 2 == 1).collect::<Vec<_>>();
+    /// assert_eq!(indices, [1, 3]);
+    /// ```
+    pub fn match_indices<'a, P>(&'a self, mut pred: P) -> impl Iterator<Item = usize> + 'a
+    where
+        P: FnMut(&T) -> bool + 'a,
+    {
+        self.iter()
+            .enumerate()
+            .filter_map(move |(idx, elem)| if pred(elem) { Some(idx) } else { None })
+    }
+
+    /// Returns an iterator over the indices of the elements starting in a
+    /// different memory page than the previous element, based on their
+    /// address. The first element is always included.
+    ///
+    /// Panics if `page_size` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0u8; 16];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.page_offsets(4096).next(), Some(0));
+    /// ```
+    pub fn page_offsets(&self, page_size: usize) -> impl Iterator<Item = usize> {
+        assert!(page_size != 0);
+        let (base, len, reversed) = (self.memory_start() as usize, self.len, self.reversed);
+        let size = mem::size_of::<T>();
+        let page = move |idx: usize| {
+            let idx = if reversed { len - 1 - idx } else { idx };
+            (base + idx * size) / page_size
+        };
+        (0..len).filter(move |&idx| idx == 0 || page(idx) != page(idx - 1))
+    }
+
+    /// Clones the data into a [`Vec`] using the given allocator. The destructor
+    /// runs before this method returns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #![feature(allocator_api)]
+    ///
+    /// use c_vec::CVec;
+    /// use std::alloc::Global;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let v = cvec.into_vec_in(Global);
+    /// assert_eq!(v, [0, 1, 2]);
+    /// ```
+    #[cfg(feature = "nightly")]
+    pub fn into_vec_in<A: std::alloc::Allocator>(self, alloc: A) -> Vec<T, A>
+    where
+        T: Clone,
+    {
+        let mut v = Vec::with_capacity_in(self.len, alloc);
+        v.extend(self.iter().cloned());
+        v
+    }
+
+    /// Returns a mutable iterator over `CVec` data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// for elem in cvec.iter_mut() {
+    ///     *elem += 1;
     /// }
     /// assert_eq!(cvec[0], 1);
     /// ```
@@ -388,17 +3027,335 @@ impl<T> CVec<T> {
     }
 }
 
+impl<T> CVec<T> {
+    // Returns a pointer to the element at the given logical index.
+    pub(crate) unsafe fn ptr_at(&self, ofs: usize) -> *mut T {
+        if self.reversed {
+            self.memory_start().add(self.len - 1 - ofs)
+        } else {
+            self.base.add(ofs)
+        }
+    }
+
+    // Returns a pointer to the first element in memory order.
+    fn memory_start(&self) -> *mut T {
+        unsafe { self.base.add(self.start) }
+    }
+
+    // Calls `f` with a `CSlice` over the elements in logical order, cloning
+    // them first if the vector is reversed.
+    fn with_logical_cslice<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(CSlice<'_, T>) -> R,
+        T: Clone,
+    {
+        if !self.reversed {
+            return f(self.as_cslice());
+        }
+        let elems = self.iter().cloned().collect::<Vec<_>>();
+        f(CSlice {
+            base: elems.as_ptr(),
+            len: elems.len(),
+            _phantom: PhantomData,
+        })
+    }
+
+    // The views in memory order would expose the elements of a reversed vector
+    // in the wrong order.
+    fn assert_memory_order(&self) {
+        assert!(
+            !self.reversed,
+            "a reversed `CVec` can't be viewed in memory order"
+        );
+    }
+}
+
+macro_rules! read_int {
+    ($($name:ident => $ty:ident, $endian:literal, $from_bytes:ident, $bytes:literal;)*) => {
+        $(
+            #[doc = concat!(
+                "Reads a `", stringify!($ty), "` stored in ", $endian,
+                " endian at the given byte offset, returning [`None`] if the vector is too short.",
+            )]
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// use c_vec::CVec;
+            ///
+            #[doc = concat!("let slice = &mut ", $bytes, ";")]
+            /// let ptr = slice.as_mut_ptr();
+            /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+            #[doc = concat!("assert_eq!(cvec.", stringify!($name), "(1), Some(1));")]
+            /// ```
+            pub fn $name(&self, ofs: usize) -> Option<$ty> {
+                if !self.reversed {
+                    return self.as_cslice().$name(ofs);
+                }
+                let mut bytes = [0; mem::size_of::<$ty>()];
+                for (pos, byte) in bytes.iter_mut().enumerate() {
+                    *byte = *self.get(ofs.checked_add(pos)?)?;
+                }
+                Some($ty::$from_bytes(bytes))
+            }
+        )*
+    };
+}
+
+macro_rules! write_int {
+    ($($name:ident => $ty:ident, $endian:literal, $to_bytes:ident, $read:ident;)*) => {
+        $(
+            #[doc = concat!(
+                "Writes a `", stringify!($ty), "` in ", $endian,
+                " endian at the given byte offset, returning `false` (and writing nothing) if",
+                " the vector is too short.",
+            )]
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// use c_vec::CVec;
+            ///
+            /// let slice = &mut [0u8; 9];
+            /// let ptr = slice.as_mut_ptr();
+            /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+            #[doc = concat!("assert!(cvec.", stringify!($name), "(1, 7));")]
+            #[doc = concat!("assert_eq!(cvec.", stringify!($read), "(1), Some(7));")]
+            /// ```
+            pub fn $name(&mut self, ofs: usize, val: $ty) -> bool {
+                if !self.reversed {
+                    return self.as_cslice_mut().$name(ofs, val);
+                }
+                let bytes = val.$to_bytes();
+                match ofs.checked_add(bytes.len()) {
+                    Some(end) if end <= self.len => {}
+                    _ => return false,
+                }
+                for (pos, byte) in bytes.iter().enumerate() {
+                    self[ofs + pos] = *byte;
+                }
+                true
+            }
+        )*
+    };
+}
+
+impl CVec<u8> {
+    write_int! {
+        write_u16_le => u16, "little", to_le_bytes, read_u16_le;
+        write_u16_be => u16, "big", to_be_bytes, read_u16_be;
+        write_u32_le => u32, "little", to_le_bytes, read_u32_le;
+        write_u32_be => u32, "big", to_be_bytes, read_u32_be;
+        write_u64_le => u64, "little", to_le_bytes, read_u64_le;
+        write_u64_be => u64, "big", to_be_bytes, read_u64_be;
+    }
+
+    read_int! {
+        read_u16_le => u16, "little", from_le_bytes, "[0u8, 1, 0]";
+        read_u16_be => u16, "big", from_be_bytes, "[0u8, 0, 1]";
+        read_u32_le => u32, "little", from_le_bytes, "[0u8, 1, 0, 0, 0]";
+        read_u32_be => u32, "big", from_be_bytes, "[0u8, 0, 0, 0, 1]";
+        read_u64_le => u64, "little", from_le_bytes, "[0u8, 1, 0, 0, 0, 0, 0, 0, 0]";
+        read_u64_be => u64, "big", from_be_bytes, "[0u8, 0, 0, 0, 0, 0, 0, 0, 1]";
+    }
+
+    /// Returns a [`CReadCursor`] reading bytes from this vector, starting at
+    /// its beginning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    /// use std::io::Read;
+    ///
+    /// let slice = &mut b"hello".to_owned();
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let mut s = String::new();
+    /// cvec.reader().read_to_string(&mut s).unwrap();
+    /// assert_eq!(s, "hello");
+    /// ```
+    pub fn reader(&self) -> CReadCursor<'_> {
+        self.as_cslice().reader()
+    }
+
+    /// Computes the CRC-32 (as used by zlib or PNG) of the bytes of the
+    /// vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut b"123456789".to_owned();
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.checksum_u32(), 0xCBF43926);
+    /// ```
+    pub fn checksum_u32(&self) -> u32 {
+        self.with_logical_cslice(|cslice| cslice.checksum_u32())
+    }
+
+    /// Computes the checksum of the bytes of the vector with the given
+    /// algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::checksum::Additive;
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [1u8, 2, 3];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.checksum(Additive::default()), 6);
+    /// ```
+    pub fn checksum<C: Checksum>(&self, algo: C) -> u64 {
+        self.with_logical_cslice(|cslice| cslice.checksum(algo))
+    }
+
+    /// Computes the 64-bit FNV-1a hash of the bytes of the vector. Unlike
+    /// [`Hash`](std::hash::Hash), the result is the same on every platform.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut b"hello".to_owned();
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.fnv1a_hash(), 0xa430d84680aabd0b);
+    /// ```
+    pub fn fnv1a_hash(&self) -> u64 {
+        self.with_logical_cslice(|cslice| cslice.fnv1a_hash())
+    }
+
+    /// Splits the vector into a prefix, a middle part of `usize` words
+    /// correctly aligned and a suffix, to process most of the bytes a word at a
+    /// time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0u8; 64];
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let (head, words, tail) = cvec.words();
+    /// assert_eq!(head.len() + words.len() * std::mem::size_of::<usize>() + tail.len(), 64);
+    /// ```
+    pub fn words(&self) -> (CSlice<'_, u8>, CSlice<'_, usize>, CSlice<'_, u8>) {
+        self.as_cslice().words()
+    }
+
+    /// Splits the vector into a header of type `H` read from its first bytes
+    /// and a [`CSlice`] over the remaining bytes.
+    ///
+    /// Returns [`None`] if the vector is shorter than `H` or if its start
+    /// isn't correctly aligned for `H`.
+    ///
+    /// # Safety
+    ///
+    /// The first `size_of::<H>()` bytes must be a valid `H`, which is usually a
+    /// `#[repr(C)]` type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// #[repr(C)]
+    /// struct Header {
+    ///     magic: [u8; 3],
+    ///     version: u8,
+    /// }
+    ///
+    /// let slice = &mut b"CV\x002payload".to_owned();
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let (header, body) = unsafe { cvec.split_header::<Header>().unwrap() };
+    /// assert_eq!(&header.magic, b"CV\x00");
+    /// assert_eq!(header.version, b'2');
+    /// assert_eq!(body.as_ref(), b"payload");
+    /// ```
+    pub unsafe fn split_header<H>(&self) -> Option<(&H, CSlice<'_, u8>)> {
+        self.as_cslice().split_header()
+    }
+
+    /// Copies the bytes of the vector into a new [`CString`].
+    ///
+    /// Returns an error if the vector contains a nul byte.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut b"hello".to_owned();
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.to_cstring().unwrap().as_bytes(), b"hello");
+    /// ```
+    pub fn to_cstring(&self) -> Result<CString, NulError> {
+        self.with_logical_cslice(|cslice| cslice.to_cstring())
+    }
+
+    /// Returns an `xxd`-like dump of the vector: each line contains the offset
+    /// of its first byte, up to 16 bytes in hexadecimal and their ASCII
+    /// representation, non-printable characters being replaced with `.`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut b"Hello!".to_owned();
+    /// let ptr = slice.as_mut_ptr();
+    /// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(
+    ///     cvec.hexdump(),
+    ///     "00000000: 4865 6c6c 6f21                           Hello!\n",
+    /// );
+    /// ```
+    pub fn hexdump(&self) -> String {
+        self.with_logical_cslice(|cslice| cslice.hexdump())
+    }
+
+    /// Returns a [`CWriteCursor`] writing bytes into this vector, starting at
+    /// its beginning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    /// use std::io::Write;
+    ///
+    /// let slice = &mut [0u8; 8];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// write!(cvec.writer(), "{}", 42).unwrap();
+    /// assert_eq!(&cvec.as_ref()[..2], b"42");
+    /// ```
+    pub fn writer(&mut self) -> CWriteCursor<'_> {
+        CWriteCursor::new(self.as_cslice_mut())
+    }
+}
+
 impl<T> AsRef<[T]> for CVec<T> {
-    /// View the stored data as a slice.
+    /// View the stored data as a slice, in memory order (see
+    /// [`CVec::as_memory_cslice`]).
     fn as_ref(&self) -> &[T] {
-        unsafe { slice::from_raw_parts(self.base as *const T, self.len) }
+        unsafe { slice::from_raw_parts(self.memory_start() as *const T, self.len) }
     }
 }
 
 impl<T> AsMut<[T]> for CVec<T> {
-    /// View the stored data as a slice.
+    /// View the stored data as a slice, in memory order (see
+    /// [`CVec::as_memory_cslice_mut`]).
     fn as_mut(&mut self) -> &mut [T] {
-        unsafe { slice::from_raw_parts_mut(self.base, self.len) }
+        unsafe { slice::from_raw_parts_mut(self.memory_start(), self.len) }
     }
 }
 
@@ -407,19 +3364,30 @@ impl<T> Index<usize> for CVec<T> {
 
     fn index(&self, index: usize) -> &T {
         assert!(index < self.len);
-        unsafe { &*self.base.add(index) }
+        unsafe { &*self.ptr_at(index) }
     }
 }
 
 impl<T> IndexMut<usize> for CVec<T> {
     fn index_mut(&mut self, index: usize) -> &mut T {
         assert!(index < self.len);
-        unsafe { &mut *self.base.add(index) }
+        unsafe { &mut *self.ptr_at(index) }
+    }
+}
+
+impl<T> From<Vec<T>> for CVec<T> {
+    fn from(vec: Vec<T>) -> CVec<T> {
+        let mut vec = mem::ManuallyDrop::new(vec);
+        let (base, len, cap) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+        let mut cvec = unsafe { CVec::new(base, len) };
+        cvec.dtor = Some(Dtor::Vec(cap));
+        cvec.owns_elements = true;
+        cvec
     }
 }
 
-impl<T: Clone> Into<Vec<T>> for CVec<T> {
-    fn into(self: CVec<T>) -> Vec<T> {
-        self.as_cslice().into()
+impl<T: Clone> From<CVec<T>> for Vec<T> {
+    fn from(vec: CVec<T>) -> Vec<T> {
+        vec.iter().cloned().collect()
     }
 }
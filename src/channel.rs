@@ -0,0 +1,81 @@
+// Copyright 2012 The Rust Project Developers.
+// Copyright 2015 Guillaume Gomez
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::marker::PhantomData;
+
+/// Mutable iterator over one channel of an interleaved foreign buffer, that is
+/// over every `n`-th element starting from the channel index.
+///
+/// The views of the different channels of a buffer never overlap, so they can
+/// be used at the same time.
+///
+/// You can get them from the [`CSliceMut::channels_mut`](crate::CSliceMut::channels_mut)
+/// or [`CVec::channels_mut`](crate::CVec::channels_mut) methods.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CSliceMut;
+///
+/// let slice = &mut [1, 10, 2, 20];
+/// let ptr = slice.as_mut_ptr();
+/// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+/// let mut channels = cslice.channels_mut(2);
+/// let right = channels.pop().unwrap();
+/// for sample in right {
+///     *sample *= 2;
+/// }
+/// assert_eq!(cslice.as_ref(), [1, 20, 2, 40]);
+/// ```
+pub struct ChannelView<'a, T> {
+    base: *mut T,
+    remaining: usize,
+    stride: usize,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+// Splits the `len` elements starting at `base` into `n` interleaved channels.
+pub(crate) fn channels<'a, T>(base: *mut T, len: usize, n: usize) -> Vec<ChannelView<'a, T>> {
+    assert!(n > 0, "the number of channels must not be 0");
+    assert!(
+        len.is_multiple_of(n),
+        "the length must be a multiple of the number of channels"
+    );
+    (0..n)
+        .map(|c| ChannelView {
+            // Not dereferenced if the buffer is empty.
+            base: base.wrapping_add(c),
+            remaining: len / n,
+            stride: n,
+            _phantom: PhantomData,
+        })
+        .collect()
+}
+
+impl<'a, T> Iterator for ChannelView<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let elem = self.base;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.base = unsafe { self.base.add(self.stride) };
+        }
+        Some(unsafe { &mut *elem })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ChannelView<'a, T> {}
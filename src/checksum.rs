@@ -0,0 +1,106 @@
+// Copyright 2012 The Rust Project Developers.
+// Copyright 2015 Guillaume Gomez
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Checksum algorithms usable with [`CSlice::checksum`](crate::CSlice::checksum)
+//! and [`CVec::checksum`](crate::CVec::checksum).
+
+/// A checksum algorithm fed with bytes.
+pub trait Checksum {
+    /// Feeds `bytes` to the algorithm.
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Returns the checksum of all the bytes fed so far.
+    fn finalize(self) -> u64;
+}
+
+/// Wrapping sum of all the bytes.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::checksum::{Additive, Checksum};
+///
+/// let mut algo = Additive::default();
+/// algo.update(&[1, 2, 3]);
+/// assert_eq!(algo.finalize(), 6);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Additive(u64);
+
+impl Checksum for Additive {
+    fn update(&mut self, bytes: &[u8]) {
+        self.0 = bytes
+            .iter()
+            .fold(self.0, |sum, &byte| sum.wrapping_add(byte as u64));
+    }
+
+    fn finalize(self) -> u64 {
+        self.0
+    }
+}
+
+/// XOR of all the bytes.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::checksum::{Checksum, Xor};
+///
+/// let mut algo = Xor::default();
+/// algo.update(&[1, 2, 3]);
+/// assert_eq!(algo.finalize(), 0);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Xor(u8);
+
+impl Checksum for Xor {
+    fn update(&mut self, bytes: &[u8]) {
+        self.0 = bytes.iter().fold(self.0, |acc, &byte| acc ^ byte);
+    }
+
+    fn finalize(self) -> u64 {
+        self.0 as u64
+    }
+}
+
+/// CRC-32, as used by zlib or PNG.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::checksum::{Checksum, Crc32};
+///
+/// let mut algo = Crc32::default();
+/// algo.update(b"123456789");
+/// assert_eq!(algo.finalize(), 0xCBF43926);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Crc32(u32);
+
+impl Default for Crc32 {
+    fn default() -> Crc32 {
+        Crc32(!0)
+    }
+}
+
+impl Checksum for Crc32 {
+    fn update(&mut self, bytes: &[u8]) {
+        self.0 = bytes.iter().fold(self.0, |mut crc, &byte| {
+            crc ^= byte as u32;
+            for _ in 0..8 {
+                crc = (crc >> 1) ^ (0xEDB88320 & (crc & 1).wrapping_neg());
+            }
+            crc
+        });
+    }
+
+    fn finalize(self) -> u64 {
+        !self.0 as u64
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright 2012 The Rust Project Developers.
+// Copyright 2015 Guillaume Gomez
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp;
+use std::io;
+use std::ptr;
+
+use crate::{CSlice, CSliceMut};
+
+/// Writer over a foreign chunk of bytes implementing [`io::Write`].
+///
+/// Bytes are written one after the other starting from the beginning of the
+/// buffer. Once the buffer is full, writes are short and eventually return
+/// `Ok(0)`, which makes [`io::Write::write_all`] fail.
+///
+/// You can get it from the [`CSliceMut::writer`] or the
+/// [`CVec::writer`](crate::CVec::writer) methods.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CSliceMut;
+/// use std::io::Write;
+///
+/// let slice = &mut [0u8; 4];
+/// let ptr = slice.as_mut_ptr();
+/// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+/// let mut writer = cslice.writer();
+/// writer.write_all(b"abc").unwrap();
+/// assert_eq!(writer.position(), 3);
+/// ```
+pub struct CWriteCursor<'a> {
+    inner: CSliceMut<'a, u8>,
+    pos: usize,
+}
+
+impl<'a> CWriteCursor<'a> {
+    pub(crate) fn new(inner: CSliceMut<'a, u8>) -> CWriteCursor<'a> {
+        CWriteCursor { inner, pos: 0 }
+    }
+
+    /// Returns the number of bytes written so far.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSliceMut;
+    /// use std::io::Write;
+    ///
+    /// let slice = &mut [0u8; 4];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+    /// let mut writer = cslice.writer();
+    /// assert_eq!(writer.position(), 0);
+    /// writer.write_all(b"ab").unwrap();
+    /// assert_eq!(writer.position(), 2);
+    /// ```
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+}
+
+impl<'a> io::Write for CWriteCursor<'a> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let amt = cmp::min(buf.len(), self.inner.len() - self.pos);
+        unsafe {
+            ptr::copy_nonoverlapping(buf.as_ptr(), self.inner.base.add(self.pos), amt);
+        }
+        self.pos += amt;
+        Ok(amt)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Reader over a foreign chunk of bytes implementing [`io::Read`].
+///
+/// Bytes are read one after the other starting from the beginning of the
+/// buffer. Once all bytes have been read, `read` returns `Ok(0)`.
+///
+/// You can get it from the [`CSlice::reader`] or the
+/// [`CVec::reader`](crate::CVec::reader) methods.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CSlice;
+/// use std::io::Read;
+///
+/// let slice = &[1u8, 2, 3];
+/// let ptr = slice.as_ptr();
+/// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+/// let mut buf = [0; 2];
+/// let mut reader = cslice.reader();
+/// reader.read_exact(&mut buf).unwrap();
+/// assert_eq!(buf, [1, 2]);
+/// assert_eq!(reader.position(), 2);
+/// ```
+pub struct CReadCursor<'a> {
+    inner: CSlice<'a, u8>,
+    pos: usize,
+}
+
+impl<'a> CReadCursor<'a> {
+    pub(crate) fn new(inner: CSlice<'a, u8>) -> CReadCursor<'a> {
+        CReadCursor { inner, pos: 0 }
+    }
+
+    /// Returns the number of bytes read so far.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CSlice;
+    /// use std::io::Read;
+    ///
+    /// let slice = &[1u8, 2, 3];
+    /// let ptr = slice.as_ptr();
+    /// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+    /// let mut reader = cslice.reader();
+    /// assert_eq!(reader.position(), 0);
+    /// reader.read_exact(&mut [0; 2]).unwrap();
+    /// assert_eq!(reader.position(), 2);
+    /// ```
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+}
+
+impl<'a> io::Read for CReadCursor<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let amt = cmp::min(buf.len(), self.inner.len() - self.pos);
+        unsafe {
+            ptr::copy_nonoverlapping(self.inner.base.add(self.pos), buf.as_mut_ptr(), amt);
+        }
+        self.pos += amt;
+        Ok(amt)
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2012 The Rust Project Developers.
+// Copyright 2015 Guillaume Gomez
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Helpers to build views over common C memory layouts.
+
+use std::mem;
+
+use crate::CSlice;
+
+/// Create a [`CSlice`] over the flexible array member of a C struct, like
+/// `items` in `struct { int n; T items[]; }`.
+///
+/// `Header` is the struct without its flexible array member. The array starts
+/// right after it, at the first offset correctly aligned for `T`.
+///
+/// Panics if the given pointer is null.
+///
+/// # Safety
+///
+/// `base` must point to a `Header` followed by `len` initialized elements
+/// which remain valid for the whole lifetime of the returned slice.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::ffi::flex_array;
+///
+/// #[repr(C)]
+/// struct Header {
+///     n: u32,
+/// }
+///
+/// let buffer = [2u32, 10, 20];
+/// let header = buffer.as_ptr() as *const Header;
+/// let items = unsafe { flex_array::<Header, u32>(header, (*header).n as usize) };
+/// assert_eq!(items.as_ref(), [10, 20]);
+/// ```
+pub unsafe fn flex_array<'a, Header, T>(base: *const Header, len: usize) -> CSlice<'a, T> {
+    assert!(!base.is_null());
+    let offset = mem::size_of::<Header>().next_multiple_of(mem::align_of::<T>());
+    CSlice::new((base as *const u8).add(offset) as *const T, len)
+}
+
+/// Create a [`CSlice`] over each string of a null-terminated array of C
+/// strings, like the `argv` or `environ` arrays. The terminating NUL bytes
+/// aren't part of the returned slices.
+///
+/// Panics if the given pointer is null.
+///
+/// # Safety
+///
+/// `base` must point to an array of pointers to NUL-terminated strings, ended
+/// by a null pointer. The array and the strings must remain valid for the
+/// whole lifetime of the returned slices.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::ffi::from_str_array;
+/// use std::ptr;
+///
+/// let strings = [b"hello\0".as_ptr() as *const libc::c_char, ptr::null()];
+/// let slices = unsafe { from_str_array(strings.as_ptr()) };
+/// assert_eq!(slices.len(), 1);
+/// assert_eq!(slices[0].as_ref(), b"hello");
+/// ```
+#[cfg(feature = "libc")]
+pub unsafe fn from_str_array<'a>(base: *const *const libc::c_char) -> Vec<CSlice<'a, u8>> {
+    assert!(!base.is_null());
+    let mut ret = Vec::new();
+    let mut entry = base;
+    while !(*entry).is_null() {
+        ret.push(CSlice::from_c_str(*entry as *const u8));
+        entry = entry.add(1);
+    }
+    ret
+}
@@ -0,0 +1,134 @@
+// Copyright 2012 The Rust Project Developers.
+// Copyright 2015 Guillaume Gomez
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::ops::Index;
+
+use crate::{CSlice, CVec};
+
+/// A read-only handle over a [`CVec`]: the vector can't be mutated until the
+/// handle is dropped.
+///
+/// You can get it from the [`CVec::freeze`] method.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CVec;
+///
+/// let slice = &mut [0, 1, 2];
+/// let ptr = slice.as_mut_ptr();
+/// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+/// let frozen = cvec.freeze();
+/// assert_eq!(frozen.as_cslice().as_ref(), [0, 1, 2]);
+/// drop(frozen);
+/// cvec[0] = 3;
+/// ```
+///
+/// It doesn't give mutable access to the data:
+///
+/// ```compile_fail
+/// use c_vec::CVec;
+///
+/// let slice = &mut [0, 1, 2];
+/// let ptr = slice.as_mut_ptr();
+/// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+/// let mut frozen = cvec.freeze();
+/// frozen.as_cslice_mut();
+/// ```
+pub struct FrozenCVec<'a, T> {
+    inner: &'a mut CVec<T>,
+}
+
+impl<'a, T> FrozenCVec<'a, T> {
+    pub(crate) fn new(inner: &'a mut CVec<T>) -> FrozenCVec<'a, T> {
+        FrozenCVec { inner }
+    }
+
+    /// Returns a [`CSlice`] which is a "view" over the data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let frozen = cvec.freeze();
+    /// let cslice = frozen.as_cslice();
+    /// ```
+    pub fn as_cslice(&self) -> CSlice<'_, T> {
+        self.inner.as_cslice()
+    }
+
+    /// Retrieves an element at a given index, returning [`None`] if the requested
+    /// index is greater than the length of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// let frozen = cvec.freeze();
+    /// assert_eq!(frozen.get(1), Some(&1));
+    /// ```
+    pub fn get(&self, ofs: usize) -> Option<&T> {
+        self.inner.get(ofs)
+    }
+
+    /// Returns the number of items in the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert_eq!(cvec.freeze().len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns whether the vector is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let mut cvec = unsafe { CVec::new(ptr, slice.len()) };
+    /// assert!(!cvec.freeze().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<'a, T> AsRef<[T]> for FrozenCVec<'a, T> {
+    /// View the stored data as a slice.
+    fn as_ref(&self) -> &[T] {
+        self.inner.as_ref()
+    }
+}
+
+impl<'a, T> Index<usize> for FrozenCVec<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.inner[index]
+    }
+}
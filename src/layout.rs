@@ -0,0 +1,77 @@
+// Copyright 2012 The Rust Project Developers.
+// Copyright 2015 Guillaume Gomez
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::mem;
+
+/// Description of the memory used by a foreign buffer.
+///
+/// You can get it from the [`CSlice::layout`](crate::CSlice::layout),
+/// [`CSliceMut::layout`](crate::CSliceMut::layout) or
+/// [`CVec::layout`](crate::CVec::layout) methods.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CVec;
+///
+/// let slice = &mut [0u16, 1, 2];
+/// let ptr = slice.as_mut_ptr();
+/// let cvec = unsafe { CVec::new(ptr, slice.len()) };
+/// println!("{:?}", cvec.layout());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BufferLayout {
+    /// The size of an element, in bytes.
+    pub elem_size: usize,
+    /// The alignment of an element, in bytes.
+    pub align: usize,
+    /// The number of elements.
+    pub len: usize,
+    /// The size of the buffer, in bytes.
+    pub byte_len: usize,
+    /// The address of the first element of the buffer.
+    pub base_addr: usize,
+}
+
+impl BufferLayout {
+    pub(crate) fn new<T>(base: *const T, len: usize) -> BufferLayout {
+        BufferLayout {
+            elem_size: mem::size_of::<T>(),
+            align: mem::align_of::<T>(),
+            len,
+            byte_len: mem::size_of::<T>() * len,
+            base_addr: base as usize,
+        }
+    }
+
+    /// Returns `true` if the whole buffer lies inside the memory region of
+    /// `region_len` bytes starting at `region_start`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let region = &mut [0u32; 8];
+    /// let cvec = unsafe { CVec::new(region.as_mut_ptr().add(2), 3) };
+    /// let layout = cvec.layout();
+    /// assert!(layout.is_within(region.as_ptr() as *const u8, 32));
+    /// assert!(!layout.is_within(region.as_ptr() as *const u8, 16));
+    /// ```
+    pub fn is_within(&self, region_start: *const u8, region_len: usize) -> bool {
+        let region_start = region_start as usize;
+        match (
+            self.base_addr.checked_add(self.byte_len),
+            region_start.checked_add(region_len),
+        ) {
+            (Some(end), Some(region_end)) => self.base_addr >= region_start && end <= region_end,
+            _ => false,
+        }
+    }
+}
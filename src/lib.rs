@@ -45,24 +45,55 @@
 //! }
 //! ```
 
+#![cfg_attr(feature = "nightly", feature(allocator_api))]
+
 #[cfg(test)]
 #[macro_use]
 extern crate doc_comment;
+#[cfg(feature = "libc")]
+extern crate libc;
 
 #[cfg(test)]
 doctest!("../README.md");
 
+pub mod checksum;
+pub mod ffi;
+#[cfg(feature = "test-util")]
+#[macro_use]
+pub mod test_util;
+pub mod util;
+
+#[cfg(feature = "atomic")]
+mod atomic;
+mod builder;
+mod c_array;
 mod c_slice;
 mod c_vec;
+mod channel;
+mod cursor;
+mod frozen;
+mod layout;
+mod restore;
+mod shared;
 
+#[cfg(feature = "atomic")]
+pub use atomic::*;
+pub use builder::*;
+pub use c_array::*;
 pub use c_slice::*;
 pub use c_vec::*;
+pub use channel::ChannelView;
+pub use cursor::*;
+pub use frozen::*;
+pub use layout::*;
+pub use restore::*;
+pub use shared::*;
 
 #[cfg(test)]
 mod tests {
     extern crate libc;
 
-    use super::{CSlice, CVec};
+    use super::{CArray, CSlice, CSliceMut, CVec};
     use std::ptr;
 
     // allocation of CVec
@@ -155,10 +186,10 @@ mod tests {
     #[test]
     fn vec_test_unwrap() {
         unsafe {
-            let cv =
-                CVec::new_with_dtor(1 as *mut isize, 0, |_| panic!("Don't run this destructor!"));
+            let ptr = ptr::NonNull::<isize>::dangling().as_ptr();
+            let cv = CVec::new_with_dtor(ptr, 0, |_| panic!("Don't run this destructor!"));
             let p = cv.into_inner();
-            assert_eq!(p, 1 as *mut isize);
+            assert_eq!(p, ptr);
         }
     }
 
@@ -235,4 +266,2024 @@ mod tests {
         assert_eq!(iter.next(), None);
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn vec_cast() {
+        let mut cv = v_malloc(4);
+        for (i, elem) in cv.iter_mut().enumerate() {
+            *elem = i as u8;
+        }
+        let cv: CVec<[u8; 1]> = unsafe { cv.cast() };
+        assert_eq!(cv.len(), 4);
+        assert_eq!(cv[0], [0]);
+        assert_eq!(cv[3], [3]);
+    }
+
+    #[test]
+    fn vec_cast_keeps_dtor() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static FREED: AtomicUsize = AtomicUsize::new(0);
+
+        fn free(base: *mut u32) {
+            FREED.store(base as usize, Ordering::SeqCst);
+        }
+
+        let mut data = [1u32, 2];
+        let base = data.as_mut_ptr();
+        let cv: CVec<i32> = unsafe { CVec::new_with_fn(base, 2, free).cast() };
+        assert_eq!(cv[1], 2);
+        drop(cv);
+        assert_eq!(FREED.load(Ordering::SeqCst), base as usize);
+
+        let cv: CVec<i32> = unsafe { CVec::from(vec![1u32, 2]).cast() };
+        assert_eq!(cv.reclaim_vec().unwrap(), [1, 2]);
+
+        // Elements which aren't `'static` can be cast as well.
+        let (a, b) = (1u8, 2u8);
+        let refs = &mut [&a, &b];
+        let cv = unsafe { CVec::new(refs.as_mut_ptr(), refs.len()) };
+        let cv: CVec<*const u8> = unsafe { cv.cast() };
+        assert_eq!(unsafe { *cv[1] }, 2);
+    }
+
+    #[test]
+    fn iter_rev() {
+        let mut cv = v_malloc(3);
+        cv[0] = 1;
+        cv[1] = 2;
+        cv[2] = 3;
+
+        let mut iter = cv.iter_rev();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+
+        let slice = cv.as_cslice();
+        let v: Vec<_> = slice.iter_rev().cloned().collect();
+        assert_eq!(v, [3, 2, 1]);
+    }
+
+    #[test]
+    fn write_cursor() {
+        use std::io::Write;
+
+        let mut cv = v_malloc(8);
+        {
+            let name = "ab";
+            let mut writer = cv.writer();
+            write!(writer, "{}-{}", 12, name).unwrap();
+            assert_eq!(writer.position(), 5);
+        }
+        assert_eq!(&cv.as_ref()[..5], b"12-ab");
+
+        let mut writer = cv.writer();
+        assert_eq!(writer.write(b"0123456789").unwrap(), 8);
+        assert_eq!(writer.write(b"0").unwrap(), 0);
+        assert!(write!(writer, "{}", 1).is_err());
+        assert_eq!(cv.as_ref(), b"01234567");
+    }
+
+    #[test]
+    fn read_cursor() {
+        use std::io::Read;
+
+        let mut cv = v_malloc(6);
+        cv.as_mut().copy_from_slice(b"c_vec!");
+
+        let mut buf = Vec::new();
+        let mut reader = cv.reader();
+        assert_eq!(reader.read_to_end(&mut buf).unwrap(), 6);
+        assert_eq!(buf, b"c_vec!");
+        assert_eq!(reader.read(&mut [0; 4]).unwrap(), 0);
+
+        let slice = cv.as_cslice();
+        let mut buf = [0; 4];
+        assert_eq!(slice.reader().read(&mut buf).unwrap(), 4);
+        assert_eq!(&buf, b"c_ve");
+    }
+
+    #[test]
+    fn get_many_mut() {
+        let mut cv = v_malloc(4);
+        for (i, elem) in cv.iter_mut().enumerate() {
+            *elem = i as u8;
+        }
+
+        {
+            let [a, b, c] = cv.get_many_mut([3, 0, 2]).unwrap();
+            *a += 10;
+            *b += 20;
+            *c += 30;
+        }
+        assert_eq!(cv.as_ref(), [20, 1, 32, 13]);
+        assert!(cv.get_many_mut([0, 2, 0]).is_none());
+        assert!(cv.get_many_mut([0, 4]).is_none());
+
+        let mut cs = cv.as_cslice_mut();
+        assert!(cs.get_many_mut([1, 3, 1]).is_none());
+        let [a, b] = cs.get_many_mut([1, 3]).unwrap();
+        std::mem::swap(a, b);
+        assert_eq!(cs.as_ref(), [20, 13, 32, 1]);
+    }
+
+    #[test]
+    fn vec_truncate() {
+        let mut cv = v_malloc(10);
+        for (i, elem) in cv.iter_mut().enumerate() {
+            *elem = i as u8;
+        }
+
+        cv.truncate(3);
+        assert_eq!(cv.len(), 3);
+        assert_eq!(cv.iter().count(), 3);
+        assert_eq!(cv.valid_slice().as_ref(), [0, 1, 2]);
+        assert!(cv.get(3).is_none());
+
+        cv.truncate(5);
+        assert_eq!(cv.len(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(all(feature = "debug-bounds", debug_assertions))]
+    fn vec_test_debug_bounds_get_unchecked() {
+        let mut cv = v_malloc(4);
+        cv.truncate(2);
+        unsafe {
+            // Still inside the allocation.
+            cv.get_unchecked(3);
+            cv.get_unchecked(4);
+        }
+    }
+
+    #[test]
+    fn diff_index() {
+        let mut a = v_malloc(4);
+        let mut b = v_malloc(4);
+        a.as_mut().copy_from_slice(&[1, 2, 3, 4]);
+        b.as_mut().copy_from_slice(&[1, 2, 0, 4]);
+
+        assert_eq!(a.diff_index(&b.as_cslice()), Some(2));
+        assert_eq!(a.as_cslice().diff_index(&b.as_cslice()), Some(2));
+        assert_eq!(a.diff_index(&a.as_cslice()), None);
+
+        b[2] = 3;
+        b.truncate(3);
+        assert_eq!(a.diff_index(&b.as_cslice()), Some(3));
+        assert_eq!(b.diff_index(&a.as_cslice()), Some(3));
+    }
+
+    #[test]
+    fn vec_with_slice_mut() {
+        let mut cv = v_malloc(3);
+        let sum = cv.with_slice_mut(|mut cs| {
+            for (i, elem) in cs.iter_mut().enumerate() {
+                *elem = i as u8 * 2;
+            }
+            cs.iter().map(|&x| x as u32).sum::<u32>()
+        });
+        assert_eq!(sum, 6);
+        assert_eq!(cv.as_ref(), [0, 2, 4]);
+    }
+
+    #[test]
+    #[cfg(feature = "libc")]
+    fn slice_from_c_str() {
+        let s = b"hello\0";
+        let cs = unsafe { CSlice::from_c_str(s.as_ptr()) };
+        assert_eq!(cs.len(), 5);
+        assert_eq!(cs.as_ref(), b"hello");
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "libc")]
+    fn slice_from_c_str_panic_at_null() {
+        unsafe {
+            CSlice::from_c_str(ptr::null());
+        }
+    }
+
+    #[test]
+    fn eq_prefix() {
+        let mut a = v_malloc(8);
+        let mut b = v_malloc(8);
+        a.as_mut().copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        b.as_mut().copy_from_slice(&[1, 2, 3, 4, 0, 0, 0, 0]);
+
+        assert!(a.eq_prefix(&b.as_cslice(), 4));
+        assert!(a.as_cslice().eq_prefix(&b.as_cslice(), 4));
+        assert!(!a.eq_prefix(&b.as_cslice(), 5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn eq_prefix_too_short() {
+        let a = v_malloc(8);
+        let b = v_malloc(2);
+
+        a.eq_prefix(&b.as_cslice(), 4);
+    }
+
+    #[test]
+    fn for_each_chunk() {
+        let mut data: Vec<u64> = (1..=10).collect();
+        let cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+
+        let mut total = 0;
+        let mut lens = Vec::new();
+        cv.for_each_chunk(4, |chunk| {
+            lens.push(chunk.len());
+            total += chunk.iter().sum::<u64>();
+        });
+        assert_eq!(total, 55);
+        assert_eq!(lens, [4, 4, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn for_each_chunk_zero() {
+        let cs = s_malloc!(4);
+
+        cs.for_each_chunk(0, |_| {});
+    }
+
+    #[test]
+    fn vec_pinned_slice() {
+        use std::pin::Pin;
+
+        let mut cv = v_malloc(4);
+        let base = cv.as_ref().as_ptr();
+        {
+            let mut pinned = unsafe { Pin::new(&mut cv).as_pinned_mut_slice() };
+            for elem in pinned.iter_mut() {
+                *elem = 7;
+            }
+            assert_eq!(pinned.as_ptr(), base);
+        }
+        // Moving the `CVec` doesn't move its buffer.
+        let moved = Box::new(cv);
+        let slice: &[u8] = (*moved).as_ref();
+        assert_eq!(slice.as_ptr(), base);
+        assert_eq!(slice, [7, 7, 7, 7]);
+    }
+
+    #[test]
+    fn map_neighbors() {
+        let mut cv = v_malloc(5);
+        cv.as_mut().copy_from_slice(&[3, 6, 9, 0, 3]);
+
+        let avg = cv.map_neighbors(|left, x, right| {
+            let left = left.unwrap_or(x);
+            let right = right.unwrap_or(x);
+            ((*left as u32 + *x as u32 + *right as u32) / 3) as u8
+        });
+        assert_eq!(avg, [4, 6, 5, 4, 2]);
+    }
+
+    #[test]
+    fn shared_vec() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let freed = Rc::new(Cell::new(0));
+        let mut data = [0u8, 1, 2, 3, 4, 5];
+        let shared = unsafe {
+            let freed = freed.clone();
+            CVec::new_with_dtor(data.as_mut_ptr(), data.len(), move |_| {
+                freed.set(freed.get() + 1)
+            })
+        }
+        .into_shared();
+        let other = shared.clone();
+
+        assert_eq!(shared.subslice(0..2).as_ref(), [0, 1]);
+        assert_eq!(other.subslice(2..6).as_ref(), [2, 3, 4, 5]);
+        drop(shared);
+        assert_eq!(freed.get(), 0);
+        assert_eq!(other.subslice(1..3).as_ref(), [1, 2]);
+        drop(other);
+        assert_eq!(freed.get(), 1);
+    }
+
+    #[test]
+    fn fill_pattern() {
+        let mut cv = v_malloc(10);
+        cv.fill_pattern(&[1, 2, 3]);
+        assert_eq!(cv.as_ref(), [1, 2, 3, 1, 2, 3, 1, 2, 3, 1]);
+
+        cv.as_cslice_mut().fill_pattern(&[4]);
+        assert_eq!(cv.as_ref(), [4; 10]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fill_pattern_empty() {
+        let mut cv = v_malloc(10);
+        cv.fill_pattern(&[]);
+    }
+
+    #[test]
+    fn count_all_any() {
+        let mut data = [1i32, 2, 3, 4, 6, -8, 7];
+        let mut cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+
+        assert_eq!(cv.count(|x| x % 2 == 0), 4);
+        assert_eq!(cv.as_cslice().count(|x| x % 2 == 0), 4);
+        assert_eq!(cv.as_cslice_mut().count(|x| x % 2 == 0), 4);
+        assert!(cv.any(|x| *x < 0));
+        assert!(!cv.all(|x| *x > 0));
+        assert!(cv.as_cslice().all(|x| *x < 10));
+        assert!(!cv.as_cslice_mut().any(|x| *x == 5));
+    }
+
+    #[test]
+    fn vec_new_checked() {
+        let mut data = [0u32; 4];
+        let cv = unsafe { CVec::new_checked(data.as_mut_ptr(), 4, 16) };
+        assert_eq!(cv.len(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vec_new_checked_too_long() {
+        let mut data = [0u32; 4];
+        // The length is given in bytes instead of elements.
+        unsafe {
+            CVec::new_checked(data.as_mut_ptr(), 16, 16);
+        }
+    }
+
+    #[test]
+    fn vec_into_iter_owned() {
+        let mut cv = v_malloc(4);
+        cv.as_mut().copy_from_slice(&[4, 3, 2, 1]);
+
+        let v: Vec<u8> = cv.into_iter_owned().collect();
+        assert_eq!(v, [4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn vec_split_owned() {
+        let mut data = [0u8, 1, 2, 3, 4];
+        let base = data.as_mut_ptr();
+        let cv = unsafe {
+            CVec::new_with_dtor(base, data.len(), |_| panic!("Don't run this destructor!"))
+        };
+
+        let (head, tail) = unsafe {
+            cv.split_owned(
+                2,
+                move |ptr| assert_eq!(ptr, base),
+                move |ptr| assert_eq!(ptr, base.add(2)),
+            )
+        };
+        assert_eq!(head.as_ref(), [0, 1]);
+        assert_eq!(tail.as_ref(), [2, 3, 4]);
+    }
+
+    #[test]
+    fn flex_array() {
+        #[repr(C)]
+        struct Header {
+            len: u32,
+        }
+
+        let mut buffer = [0u8; 4 + 3 * 2];
+        buffer[..4].copy_from_slice(&3u32.to_ne_bytes());
+        for (i, item) in buffer[4..].chunks_mut(2).enumerate() {
+            item.copy_from_slice(&(i as u16 * 100).to_ne_bytes());
+        }
+
+        let header = buffer.as_ptr() as *const Header;
+        let items = unsafe {
+            let len = ptr::read_unaligned(header).len as usize;
+            super::ffi::flex_array::<Header, [u8; 2]>(header, len)
+        };
+        assert_eq!(items.len(), 3);
+        assert_eq!(u16::from_ne_bytes(items[2]), 200);
+    }
+
+    #[test]
+    fn array_chunks() {
+        let mut cv = v_malloc(5);
+        cv.as_mut().copy_from_slice(&[1, 2, 3, 4, 5]);
+
+        let mut iter = cv.array_chunks::<2>();
+        assert_eq!(iter.next(), Some([1, 2]));
+        assert_eq!(iter.next(), Some([3, 4]));
+        assert_eq!(iter.next(), None);
+
+        let chunks: Vec<[u8; 5]> = cv.as_cslice().array_chunks().collect();
+        assert_eq!(chunks, [[1, 2, 3, 4, 5]]);
+    }
+
+    #[test]
+    fn as_rows() {
+        let mut cv = v_malloc(12);
+        for (i, elem) in cv.iter_mut().enumerate() {
+            *elem = i as u8;
+        }
+
+        {
+            let rows: &[[u8; 3]] = cv.as_rows().unwrap();
+            assert_eq!(rows.len(), 4);
+            assert_eq!(rows[2], [6, 7, 8]);
+        }
+        assert!(cv.as_rows::<5>().is_none());
+        assert!(cv.as_rows::<0>().is_none());
+
+        cv.as_cslice_mut().as_rows_mut::<3>().unwrap()[3] = [0; 3];
+        assert_eq!(&cv.as_ref()[9..], [0, 0, 0]);
+    }
+
+    #[test]
+    fn min_max_sum() {
+        let mut data = [3i32, 1, 2];
+        let cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+
+        assert_eq!(cv.min(), Some(&1));
+        assert_eq!(cv.max(), Some(&3));
+        assert_eq!(cv.sum(), 6);
+        assert_eq!(cv.as_cslice().min(), Some(&1));
+        assert_eq!(cv.as_cslice().max(), Some(&3));
+        assert_eq!(cv.as_cslice().sum(), 6);
+
+        let empty = unsafe { CVec::new(data.as_mut_ptr(), 0) };
+        assert_eq!(empty.min(), None);
+        assert_eq!(empty.max(), None);
+        assert_eq!(empty.sum(), 0);
+    }
+
+    #[test]
+    fn buf_builder() {
+        let mut cv = v_malloc(3);
+
+        let mut builder = cv.as_cslice_mut().builder();
+        assert!(builder.is_empty());
+        assert_eq!(builder.push(1), Ok(()));
+        assert_eq!(builder.push(2), Ok(()));
+        let partial = builder.finish();
+        assert_eq!(partial.as_ref(), [1, 2]);
+
+        let mut builder = cv.as_cslice_mut().builder();
+        for i in 0..3 {
+            assert_eq!(builder.push(i * 10), Ok(()));
+        }
+        assert_eq!(builder.push(30), Err(30));
+        assert_eq!(builder.len(), 3);
+        assert_eq!(builder.finish().as_ref(), [0, 10, 20]);
+        assert_eq!(cv.as_ref(), [0, 10, 20]);
+    }
+
+    #[test]
+    fn prefix_suffix() {
+        let mut cv = v_malloc(5);
+        cv.as_mut().copy_from_slice(&[1, 2, 3, 4, 5]);
+
+        assert_eq!(cv.prefix(2).as_ref(), [1, 2]);
+        assert_eq!(cv.suffix(2).as_ref(), [4, 5]);
+        assert_eq!(cv.prefix(8).as_ref(), [1, 2, 3, 4, 5]);
+        assert_eq!(cv.suffix(8).as_ref(), [1, 2, 3, 4, 5]);
+        assert!(cv.suffix(0).is_empty());
+
+        let cs = cv.as_cslice();
+        assert_eq!(cs.prefix(3).suffix(1).as_ref(), [3]);
+    }
+
+    #[test]
+    fn vec_set_len() {
+        let mut cv = v_malloc(4);
+        cv.as_mut().copy_from_slice(&[1, 2, 3, 4]);
+
+        unsafe { cv.set_len(2) };
+        assert_eq!(cv.iter().count(), 2);
+        unsafe { cv.set_len(4) };
+        assert_eq!(cv.iter().cloned().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn vec_into_boxed_slice() {
+        let boxed: Box<[String]> = vec!["a".to_owned(), "b".to_owned()].into_boxed_slice();
+        let len = boxed.len();
+        let base = Box::into_raw(boxed) as *mut String;
+        let cv =
+            unsafe { CVec::new_with_dtor(base, len, |_| panic!("Don't run this destructor!")) };
+
+        let boxed = unsafe { cv.into_boxed_slice_unchecked() };
+        assert_eq!(boxed.as_ptr(), base as *const String);
+        assert_eq!(*boxed, ["a", "b"]);
+    }
+
+    #[test]
+    fn split_into_parts() {
+        let mut cv = v_malloc(10);
+
+        let mut parts = cv.as_cslice_mut().split_into_parts(3);
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), [4, 3, 3]);
+        for (i, part) in parts.iter_mut().enumerate() {
+            for elem in part.iter_mut() {
+                *elem = i as u8;
+            }
+        }
+        assert_eq!(cv.as_ref(), [0, 0, 0, 0, 1, 1, 1, 2, 2, 2]);
+
+        let parts = cv.as_cslice_mut().split_into_parts(12);
+        assert_eq!(parts.len(), 12);
+        assert_eq!(parts.iter().map(|p| p.len()).sum::<usize>(), 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_into_zero_parts() {
+        let mut cv = v_malloc(10);
+        cv.as_cslice_mut().split_into_parts(0);
+    }
+
+    #[test]
+    fn rotate_inplace() {
+        let mut cv = v_malloc(5);
+        cv.as_mut().copy_from_slice(&[1, 2, 3, 4, 5]);
+
+        cv.rotate_left_inplace(2);
+        assert_eq!(cv.as_ref(), [3, 4, 5, 1, 2]);
+        cv.rotate_right_inplace(2);
+        assert_eq!(cv.as_ref(), [1, 2, 3, 4, 5]);
+        cv.as_cslice_mut().rotate_left_inplace(5);
+        assert_eq!(cv.as_ref(), [1, 2, 3, 4, 5]);
+        cv.as_cslice_mut().rotate_right_inplace(1);
+        assert_eq!(cv.as_ref(), [5, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotate_inplace_out_of_bounds() {
+        let mut cv = v_malloc(5);
+        cv.rotate_left_inplace(6);
+    }
+
+    #[test]
+    fn copy_from_slice() {
+        let mut cv = v_malloc(3);
+        cv.copy_from_slice(&[1, 2, 3]);
+        assert_eq!(cv.as_ref(), [1, 2, 3]);
+        cv.as_cslice_mut().copy_from_slice(&[4, 5, 6]);
+        assert_eq!(cv.as_ref(), [4, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_from_slice_wrong_len() {
+        let mut cv = v_malloc(3);
+        cv.copy_from_slice(&[1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "source and destination overlap")]
+    #[cfg(debug_assertions)]
+    fn copy_from_slice_overlap() {
+        let mut data = [1u8, 2, 3, 4];
+        let base = data.as_mut_ptr();
+        let mut dst = unsafe { CSliceMut::new(base, 3) };
+        let src = unsafe { CSlice::new(base.add(1), 3) };
+        dst.copy_from_slice(src.as_ref());
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn vec_into_vec_in() {
+        use std::alloc::{AllocError, Allocator, Layout};
+        use std::cell::{Cell, UnsafeCell};
+        use std::ptr::NonNull;
+
+        struct Bump {
+            buf: UnsafeCell<[u64; 8]>,
+            used: Cell<usize>,
+        }
+
+        unsafe impl Allocator for &Bump {
+            fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+                let base = self.buf.get() as *mut u8;
+                let start = self.used.get().next_multiple_of(layout.align());
+                if start + layout.size() > 64 {
+                    return Err(AllocError);
+                }
+                self.used.set(start + layout.size());
+                let ptr = unsafe { base.add(start) };
+                Ok(NonNull::slice_from_raw_parts(
+                    NonNull::new(ptr).unwrap(),
+                    layout.size(),
+                ))
+            }
+
+            unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+        }
+
+        let bump = Bump {
+            buf: UnsafeCell::new([0; 8]),
+            used: Cell::new(0),
+        };
+        let mut cv = v_malloc(4);
+        cv.copy_from_slice(&[1, 2, 3, 4]);
+
+        let v = cv.into_vec_in(&bump);
+        assert_eq!(v, [1, 2, 3, 4]);
+        assert_eq!(bump.used.get(), 4);
+        assert_eq!(v.as_ptr(), bump.buf.get() as *const u8);
+    }
+
+    #[test]
+    fn fold_checksum() {
+        let mut cv = v_malloc(43);
+        cv.copy_from_slice(b"The quick brown fox jumps over the lazy dog");
+
+        assert_eq!(cv.checksum_u32(), 0x414FA339);
+        assert_eq!(cv.as_cslice().checksum_u32(), 0x414FA339);
+        assert_eq!(cv.fold(0u32, |acc, &b| acc + b as u32), 4057);
+        assert_eq!(cv.prefix(0).checksum_u32(), 0);
+    }
+
+    #[test]
+    fn from_non_null() {
+        let mut data = [1u8, 2, 3];
+        let base = ptr::NonNull::new(data.as_mut_ptr()).unwrap();
+
+        let cv = unsafe { CVec::from_non_null(base, 3) };
+        assert_eq!(cv.as_ref(), [1, 2, 3]);
+        let cs = unsafe { CSlice::from_non_null(base, 2) };
+        assert_eq!(cs.as_ref(), [1, 2]);
+        let mut cs = unsafe { CSliceMut::from_non_null(base, 1) };
+        cs[0] = 4;
+        assert_eq!(data, [4, 2, 3]);
+
+        let freed = std::rc::Rc::new(std::cell::Cell::new(false));
+        let cv = unsafe {
+            let freed = freed.clone();
+            CVec::from_non_null_with_dtor(base, 3, move |ptr| {
+                assert_eq!(ptr, base.as_ptr());
+                freed.set(true);
+            })
+        };
+        drop(cv);
+        assert!(freed.get());
+    }
+
+    #[test]
+    fn interleave() {
+        let mut left = v_malloc(3);
+        let mut right = v_malloc(3);
+        left.copy_from_slice(&[1, 3, 5]);
+        right.copy_from_slice(&[2, 4, 6]);
+
+        let mixed = left.interleave(&right.as_cslice());
+        assert_eq!(mixed, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            right.as_cslice().interleave(&left.as_cslice()),
+            [2, 1, 4, 3, 6, 5]
+        );
+
+        let cs = unsafe { CSlice::new(mixed.as_ptr(), mixed.len()) };
+        let (l, r) = cs.deinterleave();
+        assert_eq!(l, left.as_ref());
+        assert_eq!(r, right.as_ref());
+    }
+
+    #[test]
+    #[should_panic]
+    fn interleave_wrong_len() {
+        let left = v_malloc(3);
+        let right = v_malloc(2);
+        left.interleave(&right.as_cslice());
+    }
+
+    #[test]
+    fn get_or() {
+        let mut cv = v_malloc(2);
+        cv.copy_from_slice(&[1, 2]);
+
+        assert_eq!(*cv.get_or(1, &0), 2);
+        assert_eq!(*cv.get_or(2, &0), 0);
+        assert_eq!(*cv.as_cslice().get_or(0, &0), 1);
+        assert_eq!(*cv.as_cslice().get_or(7, &42), 42);
+    }
+
+    #[test]
+    fn is_zeroed() {
+        let mut cv = unsafe {
+            let mem = libc::calloc(37, 1) as *mut u8;
+            CVec::new_with_dtor(mem, 37, |mem| {
+                libc::free((mem) as *mut _);
+            })
+        };
+
+        unsafe {
+            assert!(cv.is_zeroed());
+            assert!(cv.as_cslice().is_zeroed());
+            cv[36] = 1;
+            assert!(!cv.is_zeroed());
+            assert!(cv.prefix(36).is_zeroed());
+            cv[36] = 0;
+            cv[3] = 0x80;
+            assert!(!cv.is_zeroed());
+        }
+    }
+
+    #[test]
+    fn vec_reversed() {
+        let mut data = [1u8, 2, 3, 4];
+        let end = unsafe { data.as_mut_ptr().add(3) };
+        let mut cv = unsafe { CVec::new_reversed(end, 4) };
+
+        assert_eq!(cv[0], 4);
+        assert_eq!(cv[3], 1);
+        assert_eq!(cv.get(1), Some(&3));
+        assert_eq!(cv.get(4), None);
+        assert!(ptr::eq(&cv[0], end));
+        assert_eq!(cv.iter().cloned().collect::<Vec<_>>(), [4, 3, 2, 1]);
+        for (i, elem) in cv.iter_mut().enumerate() {
+            *elem = i as u8;
+        }
+        *cv.get_mut(0).unwrap() += 10;
+        assert_eq!(*cv.get_or(0, &0), 10);
+
+        cv.truncate(2);
+        assert_eq!(cv.iter().cloned().collect::<Vec<_>>(), [10, 1]);
+        assert!(ptr::eq(&cv[0], end));
+        assert_eq!(cv.layout().base_addr, end as usize - 1);
+        drop(cv);
+        assert_eq!(data, [3, 2, 1, 10]);
+    }
+
+    #[test]
+    fn vec_reversed_dtor_base() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let mut data = [1u8, 2, 3, 4];
+        let base = data.as_mut_ptr();
+        let freed = Rc::new(Cell::new(ptr::null_mut()));
+        let mut cv = unsafe { CVec::new_reversed(base.add(3), 4) };
+        let f = freed.clone();
+        unsafe { cv.set_dtor(move |base| f.set(base)) };
+        cv.truncate(1);
+        assert_eq!(cv[0], 4);
+        drop(cv);
+        assert_eq!(freed.get(), base);
+
+        let mut cv = unsafe { CVec::new_reversed(base.add(3), 4) };
+        cv.truncate(2);
+        assert_eq!(unsafe { cv.into_inner() }, base);
+    }
+
+    #[test]
+    fn vec_reversed_as_ref() {
+        let mut data = [1u8, 2, 3, 4];
+        let mut cv = unsafe { CVec::new_reversed(data.as_mut_ptr().add(3), 4) };
+        assert_eq!(cv.as_ref(), [1, 2, 3, 4]);
+        assert_eq!(cv.as_memory_cslice().as_ref(), [1, 2, 3, 4]);
+        cv.as_mut()[3] = 5;
+        assert_eq!(cv[0], 5);
+        cv.truncate(2);
+        assert_eq!(cv.as_ref(), [3, 5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vec_reversed_as_cslice() {
+        let mut data = [1u8, 2, 3, 4];
+        let cv = unsafe { CVec::new_reversed(data.as_mut_ptr().add(3), 4) };
+        cv.as_cslice();
+    }
+
+    #[test]
+    fn vec_reversed_logical_order() {
+        let mut data = [1u8, 2, 3, 4];
+        let mut cv = unsafe { CVec::new_reversed(data.as_mut_ptr().add(3), 4) };
+        let other = [4u8, 3, 0, 0];
+        let other = unsafe { CSlice::new(other.as_ptr(), other.len()) };
+
+        assert_eq!(cv.sum(), 10);
+        assert_eq!(cv.count(|&x| x > 1), 3);
+        assert_eq!(cv.to_vec_reversed(), [1, 2, 3, 4]);
+        assert_eq!(cv.read_u16_le(0), Some(0x0304));
+        assert_eq!(cv.read_u16_be(2), Some(0x0201));
+        assert_eq!(cv.read_u16_le(3), None);
+        let mut forward = [4u8, 3, 2, 1];
+        let forward = unsafe { CVec::new(forward.as_mut_ptr(), forward.len()) };
+        assert_eq!(cv.hexdump(), forward.hexdump());
+        assert_eq!(cv.checksum_u32(), forward.checksum_u32());
+        assert!(cv.eq_prefix(&other, 2));
+        assert_eq!(cv.diff_index(&other), Some(2));
+        assert!(cv.eq_iter([4, 3, 2, 1]));
+        assert_eq!(cv.iter_rev().cloned().collect::<Vec<_>>(), [1, 2, 3, 4]);
+        assert_eq!(cv.array_chunks::<2>().collect::<Vec<_>>(), [[4, 3], [2, 1]]);
+        assert_eq!(cv.pairwise().next(), Some((&4, &3)));
+        assert_eq!(
+            cv.match_indices(|&x| x % 2 == 0).collect::<Vec<_>>(),
+            [0, 2]
+        );
+        assert_eq!(
+            cv.map_neighbors(|l, _, _| l.copied().unwrap_or(0)),
+            [0, 4, 3, 2]
+        );
+
+        assert!(cv.write_u16_be(1, 0x0506));
+        assert_eq!(data, [1, 6, 5, 4]);
+        let mut cv = unsafe { CVec::new_reversed(data.as_mut_ptr().add(3), 4) };
+        cv.rotate_left_inplace(1);
+        assert_eq!(cv.iter().cloned().collect::<Vec<_>>(), [5, 6, 1, 4]);
+        cv.copy_from_slice(&[1, 2, 3, 4]);
+        assert_eq!(Vec::from(cv), [1, 2, 3, 4]);
+        assert_eq!(data, [4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn vec_reversed_set_len() {
+        let mut data = [1u8, 2, 3, 4];
+        let mut cv = unsafe { CVec::new_reversed(data.as_mut_ptr().add(3), 4) };
+        cv.truncate(1);
+        assert_eq!(cv.as_ref(), [4]);
+        unsafe { cv.set_len(4) };
+        assert_eq!(cv.iter().cloned().collect::<Vec<_>>(), [4, 3, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vec_reversed_set_len_too_long() {
+        let mut data = [1u8, 2, 3, 4];
+        let mut cv = unsafe { CVec::new_reversed(data.as_mut_ptr().add(3), 3) };
+        unsafe { cv.set_len(4) };
+    }
+
+    #[test]
+    fn to_cstring() {
+        let mut hello = *b"hello";
+        let cv = unsafe { CVec::new(hello.as_mut_ptr(), hello.len()) };
+        assert_eq!(cv.to_cstring().unwrap().as_bytes(), b"hello");
+
+        let nul = b"he\0lo";
+        let cs = unsafe { CSlice::new(nul.as_ptr(), nul.len()) };
+        assert_eq!(cs.to_cstring().unwrap_err().nul_position(), 2);
+    }
+
+    #[test]
+    fn new_with_fn() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static FREED: AtomicUsize = AtomicUsize::new(0);
+
+        fn free(base: *mut u8) {
+            FREED.store(base as usize, Ordering::SeqCst);
+        }
+
+        let mut data = [1u8, 2, 3];
+        let base = data.as_mut_ptr();
+        let cv = unsafe { CVec::new_with_fn(base, data.len(), free) };
+        assert_eq!(cv[1], 2);
+        assert_eq!(FREED.load(Ordering::SeqCst), 0);
+        drop(cv);
+        assert_eq!(FREED.load(Ordering::SeqCst), base as usize);
+    }
+
+    #[test]
+    fn as_cslice_mut_bound() {
+        fn view<'g>(cv: &CVec<u8>, guard: &'g ()) -> CSliceMut<'g, u8> {
+            unsafe { cv.as_cslice_mut_bound(guard) }
+        }
+
+        let guard = ();
+        let cv = v_malloc(4);
+        let mut view = view(&cv, &guard);
+        for (i, elem) in view.iter_mut().enumerate() {
+            *elem = i as u8;
+        }
+        assert_eq!(view.len(), 4);
+        assert_eq!(cv.as_ref(), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn offset_of() {
+        let cv = v_malloc(5);
+        assert_eq!(cv.offset_of(&cv[3]), Some(3));
+        assert_eq!(cv.offset_of(&cv[0]), Some(0));
+        assert_eq!(cv.offset_of(&0), None);
+
+        let data = [0u32, 1, 2, 3];
+        let cs = unsafe { CSlice::new(data.as_ptr(), 3) };
+        assert_eq!(cs.offset_of(&data[1]), Some(1));
+        assert_eq!(cs.offset_of(&data[3]), None);
+
+        let mut data = [0u8, 1, 2, 3];
+        let end = unsafe { data.as_mut_ptr().add(3) };
+        let cv = unsafe { CVec::new_reversed(end, 4) };
+        assert_eq!(cv.offset_of(&cv[1]), Some(1));
+    }
+
+    #[test]
+    fn split() {
+        let data = b"a,b,,c";
+        let cs = unsafe { CSlice::new(data.as_ptr(), data.len()) };
+        let parts = cs
+            .split(|b| *b == b',')
+            .map(|part| part.as_ref().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(parts, [&b"a"[..], b"b", b"", b"c"]);
+
+        let data = b",a,";
+        let cs = unsafe { CSlice::new(data.as_ptr(), data.len()) };
+        assert_eq!(
+            cs.split(|b| *b == b',')
+                .map(|p| p.len())
+                .collect::<Vec<_>>(),
+            [0, 1, 0]
+        );
+
+        let cv = v_malloc(0);
+        assert_eq!(cv.split(|_| true).count(), 1);
+    }
+
+    #[test]
+    fn join() {
+        let data = [1u8, 2, 3, 4, 5, 6];
+        let parts = (0..3)
+            .map(|i| unsafe { CSlice::new(data.as_ptr().add(i * 2), 2) })
+            .collect::<Vec<_>>();
+        let joined = super::util::join(&parts, &[0]);
+        assert_eq!(joined.len(), 8);
+        assert_eq!(joined, [1, 2, 0, 3, 4, 0, 5, 6]);
+        assert!(super::util::join::<u8>(&[], &[0]).is_empty());
+    }
+
+    #[test]
+    fn iter_copied() {
+        let mut cv = v_malloc(4);
+        cv.as_mut().copy_from_slice(&[1, 2, 3, 4]);
+        let mut iter = cv.iter_copied();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(cv.iter_copied().collect::<Vec<u8>>(), [1, 2, 3, 4]);
+
+        let cs = cv.as_cslice();
+        assert_eq!(cs.iter_copied().len(), 4);
+        assert_eq!(cs.iter_copied().collect::<Vec<u8>>(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn into_ptr_len() {
+        let mut cv = v_malloc(5);
+        cv.as_mut().copy_from_slice(b"hello");
+        let (ptr, len) = unsafe { cv.into_ptr_len() };
+        assert_eq!(len, 5);
+
+        let cv = unsafe {
+            CVec::new_with_dtor(ptr, len, |ptr| {
+                libc::free(ptr as *mut libc::c_void);
+            })
+        };
+        assert_eq!(cv.as_ref(), b"hello");
+    }
+
+    #[test]
+    fn to_vec_padded() {
+        let mut cv = v_malloc(3);
+        cv.as_mut().copy_from_slice(&[1, 2, 3]);
+        assert_eq!(cv.to_vec_padded(8, 0), [1, 2, 3, 0, 0, 0, 0, 0]);
+        assert_eq!(cv.to_vec_padded(3, 0), [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn to_vec_padded_too_short() {
+        let cv = v_malloc(3);
+        cv.to_vec_padded(2, 0);
+    }
+
+    #[test]
+    fn drop_elements() {
+        use std::mem::ManuallyDrop;
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+        let mut v = ManuallyDrop::new(vec![Rc::clone(&counter), Rc::clone(&counter)]);
+        let (base, len, cap) = (v.as_mut_ptr(), v.len(), v.capacity());
+        let mut cv = unsafe {
+            CVec::new_with_dtor(base, len, move |base| {
+                drop(Vec::from_raw_parts(base, 0, cap));
+            })
+        };
+        assert_eq!(Rc::strong_count(&counter), 3);
+        unsafe { cv.drop_elements() };
+        assert!(cv.is_empty());
+        assert_eq!(Rc::strong_count(&counter), 1);
+        drop(cv);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn new_with_element_drop() {
+        use std::mem::ManuallyDrop;
+
+        let mut v = ManuallyDrop::new(vec![String::from("hello"), String::from("world")]);
+        let (base, len, cap) = (v.as_mut_ptr(), v.len(), v.capacity());
+        let cv = unsafe {
+            CVec::new_with_element_drop(base, len, move |base| {
+                drop(Vec::from_raw_parts(base, 0, cap));
+            })
+        };
+        assert_eq!(cv[1], "world");
+        // Run under Miri to check nothing leaks.
+        drop(cv);
+    }
+
+    // Counts how many times its values are dropped.
+    #[derive(Clone)]
+    struct DropCounter(std::rc::Rc<std::cell::Cell<usize>>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    fn drop_counters(n: usize) -> (std::rc::Rc<std::cell::Cell<usize>>, CVec<DropCounter>) {
+        let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+        let v = (0..n)
+            .map(|_| DropCounter(drops.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(v.capacity(), n);
+        (drops, CVec::from(v))
+    }
+
+    #[test]
+    fn into_inner_keeps_elements() {
+        let (drops, cv) = drop_counters(2);
+        let base = unsafe { cv.into_inner() };
+        assert_eq!(drops.get(), 0);
+        drop(unsafe { Vec::from_raw_parts(base, 2, 2) });
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn into_ptr_len_keeps_elements() {
+        let (drops, cv) = drop_counters(2);
+        let (base, len) = unsafe { cv.into_ptr_len() };
+        assert_eq!(drops.get(), 0);
+        drop(unsafe { Vec::from_raw_parts(base, len, 2) });
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn into_boxed_slice_unchecked_keeps_elements() {
+        let (drops, cv) = drop_counters(2);
+        let boxed = unsafe { cv.into_boxed_slice_unchecked() };
+        assert_eq!(drops.get(), 0);
+        drop(boxed);
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn cast_moves_element_ownership() {
+        let (drops, cv) = drop_counters(2);
+        let cv: CVec<DropCounter> = unsafe { cv.cast() };
+        assert_eq!(drops.get(), 0);
+        drop(cv);
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn split_owned_moves_element_ownership() {
+        let (drops, cv) = drop_counters(3);
+        // The `Vec` allocation is entirely freed by `head`, so it must be
+        // dropped last.
+        let (head, tail) =
+            unsafe { cv.split_owned(1, |base| drop(Vec::from_raw_parts(base, 0, 3)), |_| {}) };
+        assert_eq!(drops.get(), 0);
+        drop(tail);
+        assert_eq!(drops.get(), 2);
+        drop(head);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn without() {
+        let data = (0..10).collect::<Vec<u8>>();
+        let cs = unsafe { CSlice::new(data.as_ptr(), data.len()) };
+        let (before, after) = cs.without(3..6);
+        assert_eq!(before.as_ref(), [0, 1, 2]);
+        assert_eq!(after.as_ref(), [6, 7, 8, 9]);
+
+        let (before, after) = cs.without(..=8);
+        assert!(before.is_empty());
+        assert_eq!(after.as_ref(), [9]);
+
+        let cv = v_malloc(4);
+        let (before, after) = cv.without(2..);
+        assert_eq!(before.len(), 2);
+        assert!(after.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn without_out_of_bounds() {
+        let cv = v_malloc(4);
+        cv.without(2..5);
+    }
+
+    #[test]
+    fn pairwise() {
+        let mut data = [1i32, 4, 9, 16, 25];
+        let cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        let deltas = cv
+            .pairwise()
+            .map(|(prev, curr)| curr - prev)
+            .collect::<Vec<_>>();
+        assert_eq!(deltas, [3, 5, 7, 9]);
+
+        let cs = unsafe { CSlice::new(data.as_ptr(), 1) };
+        assert_eq!(cs.pairwise().count(), 0);
+    }
+
+    #[test]
+    fn new_with_owner() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        struct Owner(Rc<Cell<usize>>);
+
+        impl Drop for Owner {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let mut data = [1u8, 2, 3];
+        let cv = unsafe { CVec::new_with_owner(data.as_mut_ptr(), 3, Owner(Rc::clone(&drops))) };
+        assert_eq!(cv[2], 3);
+        assert_eq!(drops.get(), 0);
+        drop(cv);
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn select_nth_unstable() {
+        let mut data = [7i32, -2, 5, 11, 0];
+        let mut cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        let (before, median, after) = cv.select_nth_unstable(2);
+        assert_eq!(*median, 5);
+        assert!(before.iter().all(|x| *x <= 5));
+        assert!(after.iter().all(|x| *x >= 5));
+
+        let mut cs = cv.as_cslice_mut();
+        let (_, max, _) = cs.select_nth_unstable_by(0, |a, b| b.cmp(a));
+        assert_eq!(*max, 11);
+    }
+
+    #[test]
+    fn c_array() {
+        let mut data = [1u8, 2, 3, 4];
+        let mut ca = unsafe { CArray::<u8, 4>::new(data.as_mut_ptr()) };
+        assert_eq!(ca.len(), 4);
+        assert_eq!(ca[0], 1);
+        assert_eq!(ca[3], 4);
+        assert_eq!(ca.get(4), None);
+        ca[1] = 10;
+        *ca.get_mut(2).unwrap() += 10;
+        assert_eq!(ca.as_array(), &[1, 10, 13, 4]);
+        assert_eq!(
+            std::mem::size_of::<CArray<u8, 4>>(),
+            std::mem::size_of::<*mut u8>()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn c_array_index_out_of_bounds() {
+        let mut data = [1u8, 2, 3, 4];
+        let ca = unsafe { CArray::<u8, 4>::new(data.as_mut_ptr()) };
+        let _ = ca[4];
+    }
+
+    #[test]
+    #[should_panic]
+    fn c_array_null() {
+        let _ = unsafe { CArray::<u8, 4>::new(ptr::null_mut()) };
+    }
+
+    #[cfg(feature = "atomic")]
+    #[test]
+    fn atomic_load_store() {
+        use std::sync::atomic::Ordering;
+
+        let mut data = [1u32, 2, 3];
+        let mut cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        assert_eq!(cv.load_atomic(2, Ordering::Relaxed), 3);
+        cv.store_atomic(2, 42, Ordering::Relaxed);
+        assert_eq!(cv.load_atomic(2, Ordering::Relaxed), 42);
+        assert_eq!(cv[2], 42);
+    }
+
+    #[cfg(feature = "atomic")]
+    #[test]
+    #[should_panic]
+    fn atomic_store_acquire() {
+        use std::sync::atomic::Ordering;
+
+        let mut data = [1u32, 2, 3];
+        let mut cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        cv.store_atomic(0, 42, Ordering::Acquire);
+    }
+
+    #[cfg(feature = "atomic")]
+    #[test]
+    #[should_panic]
+    fn atomic_load_out_of_bounds() {
+        use std::sync::atomic::Ordering;
+
+        let mut data = [1u32, 2, 3];
+        let cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        cv.load_atomic(3, Ordering::Relaxed);
+    }
+
+    #[test]
+    fn align_to() {
+        let cv = v_malloc(16);
+        let base = cv.as_cslice().base;
+        let (head, middle, tail) = unsafe { cv.as_cslice().suffix(15).align_to::<u32>() };
+        assert_eq!(head.len() + middle.len() * 4 + tail.len(), 15);
+        assert!((middle.base as usize).is_multiple_of(std::mem::align_of::<u32>()));
+        // malloc returns memory aligned for any primitive type.
+        assert_eq!(head.len(), 3);
+        assert_eq!(middle.len(), 3);
+        assert_eq!(tail.len(), 0);
+        assert!(ptr::eq(middle.base as *const u8, unsafe { base.add(4) }));
+
+        let (head, middle, tail) = unsafe { cv.align_to::<u32>() };
+        assert!(head.is_empty() && tail.is_empty());
+        assert_eq!(middle.len(), 4);
+    }
+
+    #[test]
+    fn reset() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let drops = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&drops);
+        let mut first = [1u8, 2];
+        let mut cv = unsafe {
+            CVec::new_with_dtor(first.as_mut_ptr(), first.len(), move |_| {
+                counter.set(counter.get() + 1);
+            })
+        };
+
+        let mut second = [3u8, 4, 5];
+        unsafe { cv.reset(second.as_mut_ptr(), second.len()) };
+        assert_eq!(drops.get(), 1);
+        assert_eq!(cv.as_ref(), [3, 4, 5]);
+
+        let counter = Rc::clone(&drops);
+        let mut third = [6u8];
+        unsafe {
+            cv.reset_with_dtor(third.as_mut_ptr(), third.len(), move |_| {
+                counter.set(counter.get() + 10);
+            })
+        };
+        assert_eq!(drops.get(), 1);
+        assert_eq!(cv[0], 6);
+        drop(cv);
+        assert_eq!(drops.get(), 11);
+    }
+
+    #[test]
+    fn eq_iter() {
+        let mut data = [0i32, 1, 2];
+        let cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        assert!(cv.eq_iter(0..3));
+        assert!(!cv.eq_iter(0..2));
+        assert!(!cv.eq_iter(0..4));
+        assert!(!cv.eq_iter(vec![0, 1, 3]));
+        assert!(cv.as_cslice().prefix(0).eq_iter(None));
+    }
+
+    #[test]
+    fn partition_copy() {
+        let mut data = [1i32, 2, 3, 4];
+        let cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        let (evens, odds) = cv.partition_copy(|x| x % 2 == 0);
+        assert_eq!(evens, [2, 4]);
+        assert_eq!(odds, [1, 3]);
+    }
+
+    #[test]
+    fn has_dtor() {
+        let mut cv = v_malloc(4);
+        assert!(cv.has_dtor());
+        let dtor = cv.take_dtor().unwrap();
+        assert!(!cv.has_dtor());
+        assert!(cv.take_dtor().is_none());
+        let base = unsafe { cv.into_inner() };
+        dtor(base);
+
+        let mut data = [1u8, 2];
+        let mut cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        assert!(!cv.has_dtor());
+        unsafe { cv.set_dtor(|_| {}) };
+        assert!(cv.has_dtor());
+    }
+
+    #[test]
+    fn saturating_slice() {
+        let mut cv = v_malloc(5);
+        cv.as_mut().copy_from_slice(&[0, 1, 2, 3, 4]);
+        assert_eq!(cv.saturating_slice(2, 100).as_ref(), [2, 3, 4]);
+        assert_eq!(cv.saturating_slice(1, 3).as_ref(), [1, 2]);
+        assert!(cv.saturating_slice(4, 2).is_empty());
+        assert!(cv.saturating_slice(10, 20).is_empty());
+        let cs = cv.as_cslice();
+        assert_eq!(cs.saturating_slice(0, 5).as_ref(), cs.as_ref());
+    }
+
+    #[cfg(feature = "libc")]
+    #[test]
+    fn from_str_array() {
+        let strings = [
+            b"hello\0".as_ptr() as *const libc::c_char,
+            b"world!\0".as_ptr() as *const libc::c_char,
+            ptr::null(),
+        ];
+        let slices = unsafe { super::ffi::from_str_array(strings.as_ptr()) };
+        assert_eq!(slices.len(), 2);
+        assert_eq!(slices[0].as_ref(), b"hello");
+        assert_eq!(slices[1].as_ref(), b"world!");
+
+        let empty = [ptr::null()];
+        assert!(unsafe { super::ffi::from_str_array(empty.as_ptr()) }.is_empty());
+    }
+
+    #[test]
+    fn fnv1a_hash() {
+        let mut hello = *b"hello";
+        let cv = unsafe { CVec::new(hello.as_mut_ptr(), hello.len()) };
+        assert_eq!(cv.fnv1a_hash(), 0xa430d84680aabd0b);
+        assert_eq!(cv.as_cslice().prefix(0).fnv1a_hash(), 0xcbf29ce484222325);
+    }
+
+    #[test]
+    fn scoped_overwrite() {
+        let mut cv = v_malloc(5);
+        cv.as_mut().copy_from_slice(b"hello");
+        {
+            let guard = cv.scoped_overwrite(1, b"EL");
+            assert_eq!(guard.as_ref(), b"EL");
+        }
+        assert_eq!(cv.as_ref(), b"hello");
+
+        let guard = cv.scoped_overwrite(3, b"p!");
+        drop(guard);
+        assert_eq!(cv.as_ref(), b"hello");
+    }
+
+    #[test]
+    #[should_panic]
+    fn scoped_overwrite_too_long() {
+        let mut cv = v_malloc(5);
+        cv.scoped_overwrite(4, b"ab");
+    }
+
+    #[test]
+    fn match_indices() {
+        let data = b"a\nbc\n\nd";
+        let cs = unsafe { CSlice::new(data.as_ptr(), data.len()) };
+        assert_eq!(
+            cs.match_indices(|b| *b == b'\n').collect::<Vec<_>>(),
+            [1, 4, 5]
+        );
+
+        let cv = v_malloc(3);
+        assert_eq!(cv.match_indices(|_| false).count(), 0);
+    }
+
+    #[test]
+    fn clone_into() {
+        let mut cv = v_malloc(4);
+        cv.as_mut().copy_from_slice(&[1, 2, 3, 4]);
+        let mut target = Vec::with_capacity(8);
+        let ptr = target.as_ptr();
+        cv.clone_into(&mut target);
+        assert_eq!(target, [1, 2, 3, 4]);
+        cv.as_cslice().prefix(2).clone_into(&mut target);
+        assert_eq!(target, [1, 2]);
+        assert_eq!(target.capacity(), 8);
+        assert_eq!(target.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn as_cslice_len() {
+        let mut cv = v_malloc(16);
+        cv.as_cslice_mut_len(4).copy_from_slice(&[1, 2, 3, 4]);
+        let cs = cv.as_cslice_len(4);
+        assert_eq!(cs.len(), 4);
+        assert_eq!(cs.as_ref(), [1, 2, 3, 4]);
+        assert_eq!(cv.len(), 16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn as_cslice_len_too_long() {
+        let cv = v_malloc(16);
+        cv.as_cslice_len(17);
+    }
+
+    #[test]
+    fn checksum() {
+        use super::checksum::{Additive, Crc32, Xor};
+
+        let mut data = *b"123456789";
+        let cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        assert_eq!(cv.checksum(Additive::default()), 477);
+        assert_eq!(cv.checksum(Xor::default()), 0x31);
+        assert_eq!(cv.checksum(Crc32::default()), 0xCBF43926);
+        assert_eq!(cv.as_cslice().checksum(Crc32::default()), 0xCBF43926);
+    }
+
+    #[test]
+    fn page_offsets() {
+        let layout = std::alloc::Layout::from_size_align(3 * 4096, 4096).unwrap();
+        let base = unsafe { std::alloc::alloc(layout) };
+        assert!(!base.is_null());
+
+        // Starts 100 bytes before the end of the first page.
+        let cs = unsafe { CSlice::new(base.add(3996) as *const u32, 2048) };
+        assert_eq!(cs.page_offsets(4096).collect::<Vec<_>>(), [0, 25, 1049]);
+
+        let cs = unsafe { CSlice::new(base as *const u8, 4096) };
+        assert_eq!(cs.page_offsets(4096).collect::<Vec<_>>(), [0]);
+        assert_eq!(
+            cs.page_offsets(1024).collect::<Vec<_>>(),
+            [0, 1024, 2048, 3072]
+        );
+
+        unsafe { std::alloc::dealloc(base, layout) };
+    }
+
+    #[cfg(feature = "libc")]
+    #[test]
+    fn from_out_params() {
+        unsafe extern "C" fn get_buffer(out_ptr: *mut *mut u8, out_len: *mut libc::c_int) {
+            static mut BUFFER: [u8; 5] = [1, 2, 3, 4, 5];
+            *out_ptr = ptr::addr_of_mut!(BUFFER) as *mut u8;
+            *out_len = 5;
+        }
+
+        let mut base = ptr::null_mut();
+        let mut len = 0;
+        unsafe { get_buffer(&mut base, &mut len) };
+        let cv = unsafe { CVec::from_out_params(base, &len) };
+        assert_eq!(cv.len(), 5);
+        assert_eq!(cv.as_ref(), [1, 2, 3, 4, 5]);
+    }
+
+    #[cfg(feature = "libc")]
+    #[test]
+    #[should_panic]
+    fn from_out_params_negative() {
+        let mut data = [0u8; 2];
+        let len: libc::c_int = -1;
+        let _ = unsafe { CVec::from_out_params(data.as_mut_ptr(), &len) };
+    }
+
+    #[test]
+    fn to_vec_dedup() {
+        let mut data = [1i32, 1, 2, 2, 3];
+        let cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        assert_eq!(cv.to_vec_dedup(), [1, 2, 3]);
+        assert_eq!(cv.to_vec_dedup_sorted(), [1, 2, 3]);
+
+        let data = [3i32, 1, 3, 3, 1];
+        let cs = unsafe { CSlice::new(data.as_ptr(), data.len()) };
+        assert_eq!(cs.to_vec_dedup(), [3, 1, 3, 1]);
+        assert_eq!(cs.to_vec_dedup_sorted(), [1, 3]);
+    }
+
+    #[test]
+    fn update_with_neighbors() {
+        let mut data = [3i32, 9, 0, 6, 12, 3];
+        let mut cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        let expected = cv.as_cslice().map_neighbors(|l, x, r| match (l, r) {
+            (Some(l), Some(r)) => (l + x + r) / 3,
+            _ => *x,
+        });
+        cv.update_with_neighbors(|x, l, r| *x = (l + *x + r) / 3);
+        assert_eq!(cv.as_ref(), expected.as_slice());
+        assert_eq!(cv.as_ref(), [3, 4, 5, 6, 7, 3]);
+
+        let mut short = [1i32, 2];
+        let mut cv = unsafe { CVec::new(short.as_mut_ptr(), short.len()) };
+        cv.update_with_neighbors(|_, _, _| panic!("no interior element"));
+    }
+
+    #[test]
+    fn from_cslice_mut() {
+        let mut data = [1u8, 2, 3];
+        let cs = unsafe { CSliceMut::new(data.as_mut_ptr(), data.len()) };
+        let mut cv = unsafe { CVec::from_cslice_mut(cs) };
+        assert!(!cv.has_dtor());
+        assert_eq!(cv.as_ref(), [1, 2, 3]);
+        cv[0] = 10;
+        let cs = cv.as_cslice_mut();
+        assert_eq!(cs.as_ref(), [10, 2, 3]);
+        drop(cv);
+        assert_eq!(data, [10, 2, 3]);
+    }
+
+    #[test]
+    fn read_int() {
+        let mut data = [0xffu8, 0x78, 0x56, 0x34, 0x12, 0, 0, 0, 0];
+        let cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        assert_eq!(cv.read_u16_le(1), Some(0x5678));
+        assert_eq!(cv.read_u16_be(1), Some(0x7856));
+        assert_eq!(cv.read_u32_le(1), Some(0x12345678));
+        assert_eq!(cv.read_u32_be(1), Some(0x78563412));
+        assert_eq!(cv.read_u64_le(1), Some(0x12345678));
+        assert_eq!(cv.read_u64_be(1), Some(0x7856341200000000));
+        assert_eq!(cv.read_u64_le(2), None);
+        assert_eq!(cv.read_u16_le(8), None);
+        assert_eq!(cv.read_u16_le(usize::MAX), None);
+
+        let cs = cv.as_cslice();
+        assert_eq!(cs.read_u32_le(5), Some(0));
+        assert_eq!(cs.read_u32_be(6), None);
+    }
+
+    #[test]
+    fn write_int() {
+        let mut cv = v_malloc(10);
+        assert!(cv.write_u16_le(1, 0x1234));
+        assert_eq!(cv.read_u16_le(1), Some(0x1234));
+        assert_eq!(&cv.as_ref()[1..3], [0x34, 0x12]);
+        assert!(cv.write_u16_be(8, 0x1234));
+        assert_eq!(cv.read_u16_be(8), Some(0x1234));
+        assert_eq!(&cv.as_ref()[8..], [0x12, 0x34]);
+        assert!(cv.write_u32_le(0, 0xdeadbeef));
+        assert_eq!(cv.read_u32_le(0), Some(0xdeadbeef));
+        assert!(cv.write_u32_be(6, 0xdeadbeef));
+        assert_eq!(cv.read_u32_be(6), Some(0xdeadbeef));
+        assert!(cv.write_u64_le(2, 0x0123456789abcdef));
+        assert_eq!(cv.read_u64_le(2), Some(0x0123456789abcdef));
+        assert!(cv.write_u64_be(1, 0x0123456789abcdef));
+        assert_eq!(cv.read_u64_be(1), Some(0x0123456789abcdef));
+
+        let before = cv.as_ref().to_vec();
+        assert!(!cv.write_u64_le(3, 0));
+        assert!(!cv.as_cslice_mut().write_u16_be(9, 0));
+        assert!(!cv.write_u32_le(usize::MAX, 0));
+        assert_eq!(cv.as_ref(), before.as_slice());
+    }
+
+    #[test]
+    fn to_vec_reversed() {
+        let mut data = [1i32, 2, 3];
+        let cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        assert_eq!(cv.to_vec_reversed(), [3, 2, 1]);
+        assert_eq!(cv.as_ref(), [1, 2, 3]);
+        assert!(cv.as_cslice().prefix(0).to_vec_reversed().is_empty());
+    }
+
+    #[test]
+    fn freeze() {
+        let mut cv = v_malloc(3);
+        cv.as_mut().copy_from_slice(&[1, 2, 3]);
+        {
+            let frozen = cv.freeze();
+            assert_eq!(frozen.len(), 3);
+            assert_eq!(frozen.as_cslice().as_ref(), [1, 2, 3]);
+            assert_eq!(frozen.get(2), Some(&3));
+            assert_eq!(frozen[0], 1);
+        }
+        cv.as_cslice_mut().fill_pattern(&[0]);
+        assert_eq!(cv.as_ref(), [0, 0, 0]);
+    }
+
+    #[test]
+    fn copy_to_uninit() {
+        use std::mem::MaybeUninit;
+
+        let mut cv = v_malloc(4);
+        cv.as_mut().copy_from_slice(&[1, 2, 3, 4]);
+        let mut dst = MaybeUninit::<[u8; 4]>::uninit();
+        let dst_slice = unsafe { &mut *(dst.as_mut_ptr() as *mut [MaybeUninit<u8>; 4]) };
+        let init = cv.copy_to_uninit(dst_slice);
+        init[0] = 10;
+        let dst = unsafe { dst.assume_init() };
+        assert_eq!(dst, [10, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_to_uninit_bad_len() {
+        use std::mem::MaybeUninit;
+
+        let cv = v_malloc(4);
+        let mut dst = [MaybeUninit::uninit(); 3];
+        cv.copy_to_uninit(&mut dst);
+    }
+
+    #[test]
+    fn take_first_last() {
+        let data = [1u8, 2, 3, 4];
+        let mut cs = unsafe { CSlice::new(data.as_ptr(), data.len()) };
+        let mut taken = Vec::new();
+        while let Some(elem) = cs.take_first() {
+            taken.push(*elem);
+        }
+        assert_eq!(taken, [1, 2, 3, 4]);
+        assert!(cs.is_empty());
+        assert_eq!(cs.take_last(), None);
+
+        let mut cv = v_malloc(4);
+        cv.as_mut().copy_from_slice(&[1, 2, 3, 4]);
+        let mut cs = cv.as_cslice_mut();
+        let first = cs.take_first().unwrap();
+        let last = cs.take_last().unwrap();
+        std::mem::swap(first, last);
+        assert_eq!(cs.as_ref(), [2, 3]);
+        assert_eq!(cv.as_ref(), [4, 2, 3, 1]);
+    }
+
+    #[test]
+    fn std_iter() {
+        let mut cv = v_malloc(3);
+        cv.as_mut().copy_from_slice(&[1, 2, 3]);
+        assert_eq!(cv.std_iter().rev().cloned().collect::<Vec<_>>(), [3, 2, 1]);
+        assert_eq!(
+            cv.as_cslice().std_iter().rev().cloned().collect::<Vec<_>>(),
+            [3, 2, 1]
+        );
+        for elem in cv.std_iter_mut() {
+            *elem *= 2;
+        }
+        let mut cs = cv.as_cslice_mut();
+        assert_eq!(cs.std_iter().rev().cloned().collect::<Vec<_>>(), [6, 4, 2]);
+        cs.std_iter_mut()
+            .rev()
+            .enumerate()
+            .for_each(|(i, x)| *x = i as u8);
+        assert_eq!(cv.as_ref(), [2, 1, 0]);
+    }
+
+    #[test]
+    fn with_sentinel_len() {
+        let mut data = *b"hello\0world";
+        let strlen = |ptr: *mut u8| unsafe { (0..).take_while(|&i| *ptr.add(i) != 0).count() };
+        let cv =
+            unsafe { CVec::with_sentinel_len(data.as_mut_ptr(), usize::MAX, usize::MAX, strlen) };
+        assert_eq!(cv.len(), 5);
+        assert_eq!(cv.as_ref(), b"hello");
+
+        let cv = unsafe {
+            CVec::with_sentinel_len(data.as_mut_ptr(), 11, usize::MAX, |_| panic!("not called"))
+        };
+        assert_eq!(cv.len(), 11);
+    }
+
+    #[test]
+    fn words() {
+        use std::mem;
+
+        let word = mem::size_of::<usize>();
+        let cv = v_malloc(1024);
+        let (head, words, tail) = cv.as_cslice().suffix(1021).words();
+        assert!((words.base as usize).is_multiple_of(mem::align_of::<usize>()));
+        assert!(head.len() < word && tail.len() < word);
+        assert_eq!(head.len() + words.len() * word + tail.len(), 1021);
+        assert!(words.len() * word >= 1021 - 2 * word);
+
+        let (head, words, _) = cv.words();
+        assert!(head.is_empty());
+        assert_eq!(words.len(), 1024 / word);
+    }
+
+    #[cfg(feature = "test-util")]
+    #[test]
+    fn cvec_macro() {
+        let cv = cvec![1u8, 2, 3];
+        assert_eq!(cv.as_ref(), [1, 2, 3]);
+        assert!(cv.has_dtor());
+
+        let cv: CVec<u32> = cvec![];
+        assert!(cv.is_empty());
+
+        let cv = cvec![1.5f64, 2.5,];
+        assert_eq!(cv[1], 2.5);
+    }
+
+    #[cfg(feature = "test-util")]
+    #[test]
+    fn from_libc_malloc() {
+        let mut cv = unsafe { super::test_util::from_libc_malloc::<u64>(3) };
+        cv.as_mut().copy_from_slice(&[1, 2, 3]);
+        assert_eq!(cv.iter().sum::<u64>(), 6);
+    }
+
+    #[test]
+    fn rle() {
+        let mut data = [1i32, 1, 1, 2, 3, 3];
+        let cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        assert_eq!(cv.rle(), [(1, 3), (2, 1), (3, 2)]);
+        assert!(cv.as_cslice().prefix(0).rle().is_empty());
+    }
+
+    #[test]
+    fn layout() {
+        let mut data = [0u32; 4];
+        let cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        let layout = cv.layout();
+        assert_eq!(layout.elem_size, 4);
+        assert_eq!(layout.align, std::mem::align_of::<u32>());
+        assert_eq!(layout.len, 4);
+        assert_eq!(layout.byte_len, 16);
+        assert_eq!(layout.base_addr, data.as_ptr() as usize);
+        assert_eq!(cv.as_cslice().layout(), layout);
+        let mut cv = cv;
+        assert_eq!(cv.as_cslice_mut().layout(), layout);
+    }
+
+    #[test]
+    fn split_even() {
+        let mut cv = v_malloc(8);
+        cv.as_mut().copy_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7]);
+        let (left, right) = cv.split_even().unwrap();
+        assert_eq!(left.as_ref(), [0, 1, 2, 3]);
+        assert_eq!(right.as_ref(), [4, 5, 6, 7]);
+        assert!(cv.as_cslice().prefix(7).split_even().is_none());
+
+        let (mut left, mut right) = cv.split_even_mut().unwrap();
+        std::mem::swap(&mut left[0], &mut right[0]);
+        assert_eq!(cv.as_ref(), [4, 1, 2, 3, 0, 5, 6, 7]);
+
+        let mut odd = v_malloc(3);
+        assert!(odd.split_even_mut().is_none());
+        assert!(odd.as_cslice_mut().split_even_mut().is_none());
+    }
+
+    #[test]
+    fn replace_dtor() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut data = [1u8, 2];
+        let first_log = Rc::clone(&log);
+        let mut cv = unsafe {
+            CVec::new_with_dtor(data.as_mut_ptr(), data.len(), move |_| {
+                first_log.borrow_mut().push("first")
+            })
+        };
+        let second_log = Rc::clone(&log);
+        unsafe {
+            let first = cv.replace_dtor(|_| {}).unwrap();
+            cv.replace_dtor(move |base| {
+                second_log.borrow_mut().push("second");
+                first(base);
+            });
+        }
+        assert!(log.borrow().is_empty());
+        drop(cv);
+        assert_eq!(*log.borrow(), ["second", "first"]);
+
+        let mut cv = unsafe { CVec::new(data.as_mut_ptr(), data.len()) };
+        assert!(unsafe { cv.replace_dtor(|_| {}) }.is_none());
+        assert!(cv.has_dtor());
+    }
+
+    #[test]
+    fn try_for_each() {
+        let mut cv = v_malloc(5);
+        cv.as_mut().copy_from_slice(b"12x45");
+        let mut seen = Vec::new();
+        let res = cv.try_for_each(|b| {
+            if b.is_ascii_digit() {
+                seen.push(*b);
+                Ok(())
+            } else {
+                Err(*b)
+            }
+        });
+        assert_eq!(res, Err(b'x'));
+        assert_eq!(seen, b"12");
+        assert_eq!(cv.as_cslice().prefix(2).try_for_each(|_| Err(())), Err(()));
+        assert_eq!(cv.as_cslice().prefix(0).try_for_each(|_| Err(())), Ok(()));
+
+        let res = cv.as_cslice_mut().try_for_each_mut(|b| {
+            if b.is_ascii_digit() {
+                *b = b'0';
+                Ok(())
+            } else {
+                Err(())
+            }
+        });
+        assert!(res.is_err());
+        assert_eq!(cv.as_ref(), b"00x45");
+    }
+
+    #[test]
+    fn reclaim_vec() {
+        let mut v = Vec::with_capacity(8);
+        v.push("a".to_owned());
+        v.push("b".to_owned());
+        let ptr = v.as_ptr();
+        let mut cvec = CVec::from(v);
+        assert_eq!(cvec.len(), 2);
+        cvec[1].push('c');
+        let v = cvec.reclaim_vec().unwrap();
+        assert_eq!(v.as_ptr(), ptr);
+        assert_eq!(v.capacity(), 8);
+        assert_eq!(v, ["a", "bc"]);
+
+        // Dropping a `CVec` coming from a `Vec` frees both the elements and the buffer.
+        drop(CVec::from(v));
+
+        let cvec = v_malloc(4);
+        assert!(cvec.reclaim_vec().is_none());
+    }
+
+    #[test]
+    fn take_while_view() {
+        let mut buf = *b"2024-10";
+        let cvec = unsafe { CVec::new(buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(cvec.take_while_view(u8::is_ascii_digit).as_ref(), b"2024");
+        assert_eq!(cvec.skip_while_view(u8::is_ascii_digit).as_ref(), b"-10");
+        assert!(cvec.take_while_view(|_| false).is_empty());
+        assert!(cvec.skip_while_view(|_| true).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "catch-dtor-panic")]
+    fn catch_dtor_panic() {
+        let slice = &mut [0, 1, 2];
+        let cvec =
+            unsafe { CVec::new_with_dtor(slice.as_mut_ptr(), slice.len(), |_| panic!("dtor")) };
+        drop(cvec);
+        assert_eq!(slice, &[0, 1, 2]);
+    }
+
+    #[test]
+    fn channels_mut() {
+        let slice = &mut [1.0f32, 1.0, 2.0, 2.0, 3.0, 3.0];
+        let mut cvec = unsafe { CVec::new(slice.as_mut_ptr(), slice.len()) };
+        {
+            let mut channels = cvec.channels_mut(2);
+            assert_eq!(channels.len(), 2);
+            assert_eq!(channels[0].len(), 3);
+            let right = channels.pop().unwrap();
+            let left = channels.pop().unwrap();
+            for (l, r) in left.zip(right) {
+                *l *= 0.5;
+                *r *= 2.0;
+            }
+        }
+        assert_eq!(cvec.as_ref(), [0.5, 2.0, 1.0, 4.0, 1.5, 6.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn channels_mut_uneven() {
+        let mut cvec = v_malloc(5);
+        cvec.channels_mut(2);
+    }
+
+    #[test]
+    fn get_wrapping() {
+        let slice = &mut [0, 1, 2];
+        let mut cvec = unsafe { CVec::new(slice.as_mut_ptr(), slice.len()) };
+        assert_eq!(cvec.get_wrapping(7), Some(&1));
+        *cvec.get_wrapping_mut(5).unwrap() = 12;
+        assert_eq!(cvec.as_ref(), [0, 1, 12]);
+        assert_eq!(cvec.as_cslice().get_wrapping(6), Some(&0));
+
+        let empty = unsafe { CSlice::new(slice.as_ptr(), 0) };
+        assert_eq!(empty.get_wrapping(0), None);
+    }
+
+    #[test]
+    fn hexdump() {
+        let mut buf = *b"0123456789abcdef\x00\x7f~";
+        let cvec = unsafe { CVec::new(buf.as_mut_ptr(), buf.len()) };
+        let dump = cvec.hexdump();
+        let mut lines = dump.lines();
+        assert_eq!(
+            lines.next(),
+            Some("00000000: 3031 3233 3435 3637 3839 6162 6364 6566  0123456789abcdef"),
+        );
+        assert_eq!(
+            lines.next(),
+            Some("00000010: 007f 7e                                  ..~"),
+        );
+        assert_eq!(lines.next(), None);
+        assert_eq!(cvec.as_cslice().prefix(0).hexdump(), "");
+    }
+
+    #[test]
+    fn is_within() {
+        let arena = &mut [0u16; 16];
+        let start = arena.as_ptr() as *const u8;
+        let inside = unsafe { CVec::new(arena.as_mut_ptr().add(4), 12) };
+        assert!(inside.is_within(start, 32));
+        assert!(inside.as_cslice().is_within(unsafe { start.add(8) }, 24));
+        assert!(!inside.is_within(unsafe { start.add(10) }, 22));
+        assert!(!inside.is_within(start, 31));
+        assert!(!inside.is_within(start, usize::MAX));
+    }
+
+    #[test]
+    fn split_header() {
+        let words = &mut [
+            u32::from_ne_bytes([1, 2, 3, 4]),
+            u32::from_ne_bytes(*b"body"),
+        ];
+        let cvec = unsafe { CVec::new(words.as_mut_ptr() as *mut u8, 8) };
+        let (header, body) = unsafe { cvec.split_header::<u32>().unwrap() };
+        assert_eq!(header.to_ne_bytes(), [1, 2, 3, 4]);
+        assert_eq!(body.as_ref(), b"body");
+
+        let cslice = cvec.as_cslice();
+        assert!(unsafe { cslice.prefix(3).split_header::<u32>() }.is_none());
+        // Misaligned start.
+        assert!(unsafe { cslice.suffix(7).split_header::<u32>() }.is_none());
+        assert_eq!(
+            unsafe { cslice.split_header::<[u8; 8]>() }.unwrap().1.len(),
+            0
+        );
+    }
+
+    #[test]
+    fn into_iter_rev() {
+        let v = vec!["a".to_owned(), "b".to_owned(), "c".to_owned()];
+        let cvec = CVec::from(v);
+        let mut iter = cvec.into_iter_rev();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next().as_deref(), Some("c"));
+        assert_eq!(iter.collect::<Vec<_>>(), ["b", "a"]);
+
+        let (drops, cv) = drop_counters(3);
+        let mut iter = cv.into_iter_rev();
+        let last = iter.next().unwrap();
+        assert_eq!(drops.get(), 0);
+        drop(last);
+        assert_eq!(drops.get(), 1);
+        drop(iter);
+        assert_eq!(drops.get(), 3);
+    }
+
+    // Meant to be run under Miri to check that the moved out elements are
+    // neither leaked nor freed twice.
+    #[test]
+    fn into_iter_rev_drain() {
+        let v = (0..5).map(|i| i.to_string()).collect::<Vec<_>>();
+        let drained = CVec::from(v).into_iter_rev().collect::<Vec<_>>();
+        assert_eq!(drained, ["4", "3", "2", "1", "0"]);
+
+        let v = (0..5).map(|i| i.to_string()).collect::<Vec<_>>();
+        let mut iter = CVec::from(v).into_iter_rev();
+        assert_eq!(iter.next().as_deref(), Some("4"));
+        assert_eq!(iter.len(), 4);
+    }
+
+    #[test]
+    fn init_tail() {
+        let slice = &mut [7u8; 8];
+        let mut cvec = unsafe { CVec::new(slice.as_mut_ptr(), slice.len()) };
+        cvec.as_cslice_mut().init_tail(8, 1);
+        cvec.init_tail(3, 0);
+        assert_eq!(cvec.as_ref(), [7, 7, 7, 0, 0, 0, 0, 0]);
+
+        let slice = &mut [0, 1, 2];
+        let end = unsafe { slice.as_mut_ptr().add(2) };
+        let mut rev = unsafe { CVec::new_reversed(end, slice.len()) };
+        rev.init_tail(1, 9);
+        assert_eq!(slice, &[9, 9, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn init_tail_too_long() {
+        let mut cvec = v_malloc(4);
+        cvec.init_tail(5, 0);
+    }
+
+    #[test]
+    fn diff() {
+        let expected = &mut [1, 2, 3, 4, 5, 6];
+        let actual = &[1, 0, 3, 4, 9, 6, 7];
+        let cvec = unsafe { CVec::new(expected.as_mut_ptr(), expected.len()) };
+        let other = unsafe { CSlice::new(actual.as_ptr(), actual.len()) };
+        assert_eq!(cvec.diff(&other), [(1, 2, 0), (4, 5, 9)]);
+        assert_eq!(other.diff(&cvec.as_cslice()), [(1, 0, 2), (4, 9, 5)]);
+        assert!(cvec.diff(&cvec.as_cslice()).is_empty());
+    }
 }
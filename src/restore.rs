@@ -0,0 +1,56 @@
+// Copyright 2012 The Rust Project Developers.
+// Copyright 2015 Guillaume Gomez
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::CSliceMut;
+
+/// Guard restoring the elements overwritten by
+/// [`CSliceMut::scoped_overwrite`] or
+/// [`CVec::scoped_overwrite`](crate::CVec::scoped_overwrite) when dropped.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CSliceMut;
+///
+/// let slice = &mut [0, 1, 2];
+/// let ptr = slice.as_mut_ptr();
+/// let mut cslice = unsafe { CSliceMut::new(ptr, slice.len()) };
+/// let guard = cslice.scoped_overwrite(1, &[5]);
+/// assert_eq!(guard.as_ref(), [5]);
+/// drop(guard);
+/// assert_eq!(cslice.as_ref(), [0, 1, 2]);
+/// ```
+pub struct RestoreGuard<'a, T: Copy> {
+    inner: CSliceMut<'a, T>,
+    saved: Vec<T>,
+}
+
+impl<'a, T: Copy> RestoreGuard<'a, T> {
+    pub(crate) fn new(mut inner: CSliceMut<'a, T>, ofs: usize, data: &[T]) -> RestoreGuard<'a, T> {
+        assert!(ofs <= inner.len() && data.len() <= inner.len() - ofs);
+        inner.base = unsafe { inner.base.add(ofs) };
+        inner.len = data.len();
+        let saved = inner.as_ref().to_vec();
+        inner.copy_from_slice(data);
+        RestoreGuard { inner, saved }
+    }
+}
+
+impl<'a, T: Copy> AsRef<[T]> for RestoreGuard<'a, T> {
+    /// View the overwritten elements as a slice.
+    fn as_ref(&self) -> &[T] {
+        self.inner.as_ref()
+    }
+}
+
+impl<'a, T: Copy> Drop for RestoreGuard<'a, T> {
+    fn drop(&mut self) {
+        self.inner.copy_from_slice(&self.saved);
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright 2012 The Rust Project Developers.
+// Copyright 2015 Guillaume Gomez
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::{CSlice, CVec};
+
+/// A reference-counted [`CVec`].
+///
+/// Cloning it doesn't copy the buffer: all the clones share the same one and
+/// the destructor of the [`CVec`] runs when the last of them is dropped.
+///
+/// You can get it from the [`CVec::into_shared`] method.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::CVec;
+///
+/// let slice = &mut [0, 1, 2];
+/// let ptr = slice.as_mut_ptr();
+/// let cvec = unsafe { CVec::new_with_dtor(ptr, slice.len(), |_| println!("free time!")) };
+/// let shared = cvec.into_shared();
+/// let other = shared.clone();
+/// drop(shared);
+/// assert_eq!(other.subslice(1..3).as_ref(), [1, 2]);
+/// ```
+pub struct SharedCVec<T> {
+    inner: Rc<CVec<T>>,
+}
+
+impl<T> SharedCVec<T> {
+    pub(crate) fn new(inner: CVec<T>) -> SharedCVec<T> {
+        SharedCVec {
+            inner: Rc::new(inner),
+        }
+    }
+
+    /// Returns a [`CSlice`] over the given range of the shared buffer.
+    ///
+    /// Panics if the range is out of bounds or if the vector was created with
+    /// [`CVec::new_reversed`](crate::CVec::new_reversed).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let shared = unsafe { CVec::new(ptr, slice.len()) }.into_shared();
+    /// assert_eq!(shared.subslice(0..2).as_ref(), [0, 1]);
+    /// ```
+    pub fn subslice(&self, range: Range<usize>) -> CSlice<'_, T> {
+        assert!(range.start <= range.end && range.end <= self.len());
+        let base = self.inner.as_cslice().base;
+        CSlice {
+            base: unsafe { base.add(range.start) },
+            len: range.end - range.start,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of items in the shared buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let shared = unsafe { CVec::new(ptr, slice.len()) }.into_shared();
+    /// assert_eq!(shared.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns whether the shared buffer is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use c_vec::CVec;
+    ///
+    /// let slice = &mut [0, 1, 2];
+    /// let ptr = slice.as_mut_ptr();
+    /// let shared = unsafe { CVec::new(ptr, slice.len()) }.into_shared();
+    /// assert!(!shared.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<T> Clone for SharedCVec<T> {
+    fn clone(&self) -> SharedCVec<T> {
+        SharedCVec {
+            inner: Rc::clone(&self.inner),
+        }
+    }
+}
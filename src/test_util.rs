@@ -0,0 +1,90 @@
+// Copyright 2012 The Rust Project Developers.
+// Copyright 2015 Guillaume Gomez
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Helpers to build [`CVec`]s backed by `libc::malloc` in tests.
+
+use std::cmp;
+use std::mem;
+use std::ptr;
+
+use crate::CVec;
+
+/// Allocates a buffer of `len` elements with `libc::malloc` and wraps it into
+/// a [`CVec`] which calls `libc::free` when dropped.
+///
+/// Panics if the allocation fails or if `T` needs a greater alignment than
+/// `libc::malloc` guarantees.
+///
+/// # Safety
+///
+/// The elements are uninitialized: they must be written (with [`ptr::write`]
+/// for example) before being read.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::test_util::from_libc_malloc;
+///
+/// let cvec = unsafe { from_libc_malloc::<u32>(4) };
+/// assert_eq!(cvec.len(), 4);
+/// ```
+pub unsafe fn from_libc_malloc<T>(len: usize) -> CVec<T> {
+    assert!(mem::align_of::<T>() <= mem::align_of::<libc::max_align_t>());
+    let size = mem::size_of::<T>()
+        .checked_mul(len)
+        .expect("capacity overflow");
+    // `malloc(0)` may return a null pointer.
+    let base = libc::malloc(cmp::max(size, 1)) as *mut T;
+    assert!(!base.is_null(), "allocation failed");
+    CVec::new_with_fn(base, len, |base| libc::free(base as *mut libc::c_void))
+}
+
+/// Copies `elems` into a buffer allocated with `libc::malloc` and wraps it
+/// into a [`CVec`] which calls `libc::free` when dropped. The elements aren't
+/// dropped.
+///
+/// This is what the [`cvec!`](crate::cvec!) macro uses.
+///
+/// Panics if the allocation fails or if `T` needs a greater alignment than
+/// `libc::malloc` guarantees.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::test_util::from_libc_array;
+///
+/// let cvec = from_libc_array([1u8, 2, 3]);
+/// assert_eq!(cvec.as_ref(), [1, 2, 3]);
+/// ```
+pub fn from_libc_array<T, const N: usize>(elems: [T; N]) -> CVec<T> {
+    unsafe {
+        let mut cvec = from_libc_malloc::<T>(N);
+        let elems = mem::ManuallyDrop::new(elems);
+        ptr::copy_nonoverlapping(elems.as_ptr(), cvec.as_cslice_mut().base, N);
+        cvec
+    }
+}
+
+/// Creates a [`CVec`] containing the given elements, in a buffer allocated
+/// with `libc::malloc` and freed with `libc::free`.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::cvec;
+///
+/// let cvec = cvec![1u8, 2, 3];
+/// assert_eq!(cvec.as_ref(), [1, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! cvec {
+    ($($elem:expr),* $(,)?) => {
+        $crate::test_util::from_libc_array([$($elem),*])
+    };
+}
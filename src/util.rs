@@ -0,0 +1,39 @@
+// Copyright 2012 The Rust Project Developers.
+// Copyright 2015 Guillaume Gomez
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Helpers working on several views at once.
+
+use crate::CSlice;
+
+/// Concatenates the elements of `parts` into a new `Vec`, inserting `sep`
+/// between each of them.
+///
+/// # Example
+///
+/// ```
+/// use c_vec::util::join;
+/// use c_vec::CSlice;
+///
+/// let slice = b"ab";
+/// let ptr = slice.as_ptr();
+/// let cslice = unsafe { CSlice::new(ptr, slice.len()) };
+/// assert_eq!(join(&[cslice.prefix(1), cslice.suffix(1)], b", "), b"a, b");
+/// ```
+pub fn join<T: Clone>(parts: &[CSlice<'_, T>], sep: &[T]) -> Vec<T> {
+    let len = parts.iter().map(|part| part.len()).sum::<usize>()
+        + sep.len() * parts.len().saturating_sub(1);
+    let mut ret = Vec::with_capacity(len);
+    for (i, part) in parts.iter().enumerate() {
+        if i != 0 {
+            ret.extend_from_slice(sep);
+        }
+        ret.extend_from_slice(part.as_ref());
+    }
+    ret
+}